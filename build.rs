@@ -1,12 +1,29 @@
+use brotli::enc::BrotliEncoderParams;
+use flate2::{Compression, write::GzEncoder};
+use sha2::{Digest, Sha256};
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+/// Hex digits of the SHA-256 kept for ETags and fingerprinted file names.
+const FINGERPRINT_LEN: usize = 16;
+
+struct AssetEntry {
+    route: String,
+    fingerprinted: String,
+    mime: &'static str,
+    full: String,
+    etag: String,
+    gzip: Option<String>,
+    brotli: Option<String>,
+}
+
 fn main() -> io::Result<()> {
     let project_root =
         PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set"));
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set"));
     let static_dir = project_root.join("static");
 
     if !static_dir.exists() {
@@ -26,12 +43,36 @@ fn main() -> io::Result<()> {
                 .replace(std::path::MAIN_SEPARATOR, "/");
             let route = format!("/{}", normalized);
             let mime = mime_type(path);
-            entries.push((route, mime, normalized));
+            let bytes = fs::read(path)?;
+            let hash = hex(&Sha256::digest(&bytes));
+            let fingerprint = &hash[..FINGERPRINT_LEN];
+
+            // Images are already compressed; only text formats get precompressed variants.
+            let (gzip, brotli) = if is_compressible(mime) {
+                let target = out_dir.join("compressed").join(&normalized);
+                fs::create_dir_all(target.parent().expect("asset has a parent directory"))?;
+                (
+                    write_variant(&target, "gz", &bytes, gzip_bytes(&bytes)?)?,
+                    write_variant(&target, "br", &bytes, brotli_bytes(&bytes)?)?,
+                )
+            } else {
+                (None, None)
+            };
+
+            entries.push(AssetEntry {
+                fingerprinted: fingerprinted_route(&route, fingerprint),
+                route,
+                mime,
+                full: normalized,
+                etag: format!("\"{fingerprint}\""),
+                gzip,
+                brotli,
+            });
             println!("cargo:rerun-if-changed={}", path.display());
         }
     }
 
-    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries.sort_by(|a, b| a.route.cmp(&b.route));
 
     let mut file = File::create(project_root.join("src/generated_assets.rs"))?;
 
@@ -41,7 +82,7 @@ fn main() -> io::Result<()> {
     )?;
     writeln!(
         file,
-        "// Design reasoning: Matching paths to include_str! keeps the runtime server free of filesystem access."
+        "// Design reasoning: Hashes and gzip/brotli variants are computed at build time so serving is a table lookup."
     )?;
     writeln!(
         file,
@@ -56,15 +97,20 @@ fn main() -> io::Result<()> {
     writeln!(file, "    Binary(&'static [u8]),")?;
     writeln!(file, "}}")?;
     writeln!(file)?;
-    writeln!(
-        file,
-        "pub fn get_asset(path: &str) -> Option<(&'static str, AssetContent)> {{"
-    )?;
-    writeln!(file, "    match path {{")?;
+    writeln!(file, "pub struct Asset {{")?;
+    writeln!(file, "    pub fingerprinted_path: &'static str,")?;
+    writeln!(file, "    pub content_type: &'static str,")?;
+    writeln!(file, "    pub content: AssetContent,")?;
+    writeln!(file, "    pub etag: &'static str,")?;
+    writeln!(file, "    pub gzip: Option<&'static [u8]>,")?;
+    writeln!(file, "    pub brotli: Option<&'static [u8]>,")?;
+    writeln!(file, "}}")?;
+    writeln!(file)?;
+    writeln!(file, "pub static ASSETS: [Asset; {}] = [", entries.len())?;
 
-    for (route, mime, full) in entries {
+    for entry in &entries {
         let is_binary = matches!(
-            mime,
+            entry.mime,
             "image/png" | "image/jpeg" | "application/octet-stream"
         );
         let include = if is_binary {
@@ -73,17 +119,44 @@ fn main() -> io::Result<()> {
             "include_str!"
         };
         let variant = if is_binary { "Binary" } else { "Text" };
+        writeln!(file, "    Asset {{")?;
         writeln!(
             file,
-            "        \"{route}\" => Some((\"{mime}\", AssetContent::{variant}({include}(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/{full}\"))))),",
-            route = route,
-            mime = mime,
+            "        fingerprinted_path: \"{}\",",
+            entry.fingerprinted
+        )?;
+        writeln!(file, "        content_type: \"{}\",", entry.mime)?;
+        writeln!(
+            file,
+            "        content: AssetContent::{variant}({include}(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/{full}\"))),",
             variant = variant,
             include = include,
-            full = full.replace('\\', "/")
+            full = entry.full.replace('\\', "/")
         )?;
+        writeln!(file, "        etag: {:?},", entry.etag)?;
+        writeln!(file, "        gzip: {},", out_dir_include(&entry.gzip))?;
+        writeln!(file, "        brotli: {},", out_dir_include(&entry.brotli))?;
+        writeln!(file, "    }},")?;
     }
 
+    writeln!(file, "];")?;
+    writeln!(file)?;
+    writeln!(
+        file,
+        "/// Resolves both the plain and the fingerprinted URL of an asset."
+    )?;
+    writeln!(
+        file,
+        "pub fn get_asset(path: &str) -> Option<&'static Asset> {{"
+    )?;
+    writeln!(file, "    match path {{")?;
+    for (index, entry) in entries.iter().enumerate() {
+        writeln!(
+            file,
+            "        \"{}\" | \"{}\" => Some(&ASSETS[{index}]),",
+            entry.route, entry.fingerprinted
+        )?;
+    }
     writeln!(file, "        _ => None,")?;
     writeln!(file, "    }}")?;
     writeln!(file, "}}")?;
@@ -102,3 +175,71 @@ fn mime_type(path: &Path) -> &'static str {
         _ => "application/octet-stream",
     }
 }
+
+fn is_compressible(mime: &str) -> bool {
+    matches!(
+        mime,
+        "text/css" | "application/javascript" | "application/json" | "image/svg+xml"
+    )
+}
+
+/// `/static/js/dashboard.js` becomes `/static/js/dashboard.<fingerprint>.js`.
+fn fingerprinted_route(route: &str, fingerprint: &str) -> String {
+    let file_start = route.rfind('/').map_or(0, |index| index + 1);
+    match route[file_start..].rfind('.') {
+        Some(dot) => {
+            let dot = file_start + dot;
+            format!("{}.{fingerprint}{}", &route[..dot], &route[dot..])
+        }
+        None => format!("{route}.{fingerprint}"),
+    }
+}
+
+fn gzip_bytes(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(bytes)?;
+    encoder.finish()
+}
+
+fn brotli_bytes(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    let mut output = Vec::new();
+    let params = BrotliEncoderParams {
+        quality: 11,
+        ..Default::default()
+    };
+    brotli::BrotliCompress(&mut &bytes[..], &mut output, &params)?;
+    Ok(output)
+}
+
+/// Writes a compressed variant next to the asset's OUT_DIR path, unless it would not save bytes.
+fn write_variant(
+    target: &Path,
+    extension: &str,
+    original: &[u8],
+    compressed: Vec<u8>,
+) -> io::Result<Option<String>> {
+    if compressed.len() >= original.len() {
+        return Ok(None);
+    }
+    let path = PathBuf::from(format!("{}.{extension}", target.display()));
+    fs::write(&path, compressed)?;
+    let relative = path
+        .strip_prefix(env::var("OUT_DIR").expect("OUT_DIR is set"))
+        .expect("variant lives in OUT_DIR")
+        .to_string_lossy()
+        .replace(std::path::MAIN_SEPARATOR, "/");
+    Ok(Some(relative))
+}
+
+fn out_dir_include(relative: &Option<String>) -> String {
+    match relative {
+        Some(path) => {
+            format!("Some(include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{path}\")))")
+        }
+        None => "None".to_string(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,367 @@
+// Responsibility: Parse command-line arguments and run the one-shot CLI commands (`export`, `bundle` and `diff`).
+// Design reasoning: Hand-rolled parsing keeps the binary dependency-light; with no arguments the server starts as before.
+// Extension guidance: Add a Command variant, its flags in `parse`, a `usage` line, and a runner function below.
+// Security considerations: The CLI runs as the invoking local user, so it uses admin visibility; redaction is opt-in via --redact/--redaction.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::{DEFAULT_MODE, FORUM_POST_MAX_CHARS};
+use crate::error::AppError;
+use crate::models::SystemReport;
+use crate::rendering::{
+    ExportFormat, ExportLayout, ExportOptions, bundle_zip, markdown_diff, parse_section_names,
+};
+use crate::services::diff::{DiffRules, diff_reports};
+use crate::services::redaction::RedactionProfile;
+use crate::services::{InxiMode, InxiService};
+
+/// Help text; defaults are read from config so they cannot drift from what the parser does.
+pub fn usage() -> String {
+    format!(
+        "\
+Usage:
+  inxi-dash [serve]                 Start the dashboard server
+  inxi-dash export [options]        Collect a report and write an export
+  inxi-dash bundle [options]        Collect every mode and write a ZIP bundle with checksums
+  inxi-dash diff <old> <new> [options]
+                                    Compare two saved reports (JSON exports, API responses or snapshots)
+
+Export options:
+  --mode <basic|full|verbose|maximum>   Detail level (default: {DEFAULT_MODE})
+  --format <html|md|pdf|text|bbcode|csv|xlsx>
+                                        Export format (default: md)
+  --redact, --no-redact                 Mask identifying fields (default: only for text and bbcode)
+  --redaction <none|standard|strict>    Redaction profile; --redact is the same as standard
+  --include <names>, --exclude <names>  Comma-separated section titles or categories to keep or drop
+  --layout <detailed|compact>           Compact collapses whitespace and shortens long values
+  --text-appendix                       Append the exported sections as inxi-style text (html, md, pdf, xlsx)
+  --limit <chars>                       Character budget for text and bbcode (default: {FORUM_POST_MAX_CHARS})
+
+Bundle options:
+  --redact, --no-redact, --redaction <none|standard|strict>
+                                        As for export (default: no redaction)
+
+Diff options:
+  --format <md|json>                    Output format (default: md)
+  --ignore <rules>                      Comma-separated extra ignore rules: field, Section:field or Section:*
+  --no-volatile                         Also compare temperatures, speeds, uptime and other volatile readings
+
+Options for export, bundle and diff:
+  -o, --output <file>                   Write to a file instead of stdout"
+    )
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Serve,
+    Export(ExportArgs),
+    Bundle(BundleArgs),
+    Diff(DiffArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExportArgs {
+    pub mode: InxiMode,
+    pub format: ExportFormat,
+    /// `None` follows the format's default (`ExportFormat::redacts_by_default`).
+    pub redaction: Option<RedactionProfile>,
+    pub options: ExportOptions,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BundleArgs {
+    pub redaction: RedactionProfile,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DiffArgs {
+    pub old: PathBuf,
+    pub new: PathBuf,
+    /// Markdown when true, otherwise the JSON served by `/api/v1/diff`.
+    pub markdown: bool,
+    pub rules: DiffRules,
+    pub output: Option<PathBuf>,
+}
+
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, AppError> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        None | Some("serve") => Ok(Command::Serve),
+        Some("-h" | "--help" | "help") => Ok(Command::Help),
+        Some("export") => parse_export(args).map(Command::Export),
+        Some("bundle") => parse_bundle(args).map(Command::Bundle),
+        Some("diff") => parse_diff(args).map(Command::Diff),
+        Some(other) => Err(AppError::Usage(format!("unknown command '{other}'"))),
+    }
+}
+
+fn parse_export(mut args: impl Iterator<Item = String>) -> Result<ExportArgs, AppError> {
+    let mut export = ExportArgs {
+        mode: InxiMode::parse(DEFAULT_MODE)?,
+        format: ExportFormat::Markdown,
+        redaction: None,
+        options: ExportOptions::default(),
+        output: None,
+    };
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--mode" => export.mode = InxiMode::parse(&value(&flag, args.next())?)?,
+            "--format" => export.format = ExportFormat::parse(&value(&flag, args.next())?)?,
+            "--redact" | "--no-redact" | "--redaction" => {
+                export.redaction = Some(redaction_flag(&flag, &mut args)?)
+            }
+            "--include" => {
+                export.options.include = parse_section_names(&value(&flag, args.next())?)?
+            }
+            "--exclude" => {
+                export.options.exclude = parse_section_names(&value(&flag, args.next())?)?
+            }
+            "--layout" => export.options.layout = ExportLayout::parse(&value(&flag, args.next())?)?,
+            "--text-appendix" => export.options.text_appendix = true,
+            "--limit" => {
+                let limit = value(&flag, args.next())?;
+                let limit = limit
+                    .parse()
+                    .map_err(|_| AppError::Usage(format!("'{flag}' needs a number")))?;
+                export.options.forum_limit = ExportOptions::new(Some(limit))?.forum_limit;
+            }
+            "-o" | "--output" => export.output = Some(PathBuf::from(value(&flag, args.next())?)),
+            other => return Err(AppError::Usage(format!("unknown option '{other}'"))),
+        }
+    }
+    export.options.validate(export.format)?;
+    Ok(export)
+}
+
+fn parse_bundle(mut args: impl Iterator<Item = String>) -> Result<BundleArgs, AppError> {
+    let mut bundle = BundleArgs {
+        redaction: RedactionProfile::None,
+        output: None,
+    };
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--redact" | "--no-redact" | "--redaction" => {
+                bundle.redaction = redaction_flag(&flag, &mut args)?
+            }
+            "-o" | "--output" => bundle.output = Some(PathBuf::from(value(&flag, args.next())?)),
+            other => return Err(AppError::Usage(format!("unknown option '{other}'"))),
+        }
+    }
+    Ok(bundle)
+}
+
+fn parse_diff(mut args: impl Iterator<Item = String>) -> Result<DiffArgs, AppError> {
+    let mut paths = Vec::new();
+    let mut markdown = true;
+    let mut volatile = true;
+    let mut ignore = Vec::new();
+    let mut output = None;
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--format" => {
+                markdown = match value(&flag, args.next())?.as_str() {
+                    "md" => true,
+                    "json" => false,
+                    other => {
+                        return Err(AppError::Usage(format!(
+                            "unknown diff format '{other}'; use md or json"
+                        )));
+                    }
+                }
+            }
+            "--ignore" => ignore.extend(
+                value(&flag, args.next())?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|rule| !rule.is_empty())
+                    .map(str::to_string),
+            ),
+            "--no-volatile" => volatile = false,
+            "-o" | "--output" => output = Some(PathBuf::from(value(&flag, args.next())?)),
+            other if other.starts_with('-') => {
+                return Err(AppError::Usage(format!("unknown option '{other}'")));
+            }
+            path => paths.push(PathBuf::from(path)),
+        }
+    }
+    let [old, new] = <[PathBuf; 2]>::try_from(paths)
+        .map_err(|_| AppError::Usage("diff needs two report files: <old> <new>".to_string()))?;
+    Ok(DiffArgs {
+        old,
+        new,
+        markdown,
+        rules: DiffRules::new(volatile, &ignore)?,
+        output,
+    })
+}
+
+fn redaction_flag(
+    flag: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<RedactionProfile, AppError> {
+    match flag {
+        "--redact" => Ok(RedactionProfile::Standard),
+        "--no-redact" => Ok(RedactionProfile::None),
+        _ => RedactionProfile::parse(&value(flag, args.next())?),
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, AppError> {
+    value.ok_or_else(|| AppError::Usage(format!("'{flag}' needs a value")))
+}
+
+pub async fn export(args: ExportArgs) -> Result<(), AppError> {
+    let service = InxiService::new(None);
+    let report = service.run(args.mode).await?;
+    let options = ExportOptions {
+        redaction: args.redaction.unwrap_or(RedactionProfile::from_redact(
+            args.format.redacts_by_default(),
+        )),
+        ..args.options
+    };
+    let bytes = args.format.render(&report, &options)?;
+    write_output(args.output, &bytes, args.format.as_str())
+}
+
+/// Collects the modes one after another, like the server's `/download/bundle`.
+pub async fn bundle(args: BundleArgs) -> Result<(), AppError> {
+    let service = InxiService::new(None);
+    let mut reports = Vec::with_capacity(InxiMode::ALL.len());
+    for mode in InxiMode::ALL {
+        reports.push(service.run(mode).await?);
+    }
+    let options = ExportOptions {
+        redaction: args.redaction,
+        ..ExportOptions::default()
+    };
+    let bytes = bundle_zip(&reports, &[], &options)?;
+    write_output(args.output, &bytes, "zip")
+}
+
+/// Compares two files without running inxi, so it works on reports copied from other machines.
+pub fn diff(args: DiffArgs) -> Result<(), AppError> {
+    let diff = diff_reports(
+        &read_report(&args.old)?,
+        &read_report(&args.new)?,
+        &args.rules,
+    );
+    let (body, format) = if args.markdown {
+        (markdown_diff(&diff), "md")
+    } else {
+        let json = serde_json::to_string_pretty(&diff)
+            .map_err(|err| AppError::Io(io::Error::other(err)))?;
+        (json + "\n", "json")
+    };
+    write_output(args.output, body.as_bytes(), format)
+}
+
+/// A report document, or a saved snapshot whose `report` holds one.
+fn read_report(path: &Path) -> Result<SystemReport, AppError> {
+    let invalid = |err: serde_json::Error| AppError::Parse(format!("{}: {err}", path.display()));
+    let mut document: serde_json::Value =
+        serde_json::from_slice(&fs::read(path)?).map_err(invalid)?;
+    if let Some(report) = document
+        .get_mut("report")
+        .filter(|report| report.is_object())
+    {
+        document = report.take();
+    }
+    serde_json::from_value(document).map_err(invalid)
+}
+
+fn write_output(output: Option<PathBuf>, bytes: &[u8], format: &str) -> Result<(), AppError> {
+    match output {
+        Some(path) => {
+            fs::write(&path, bytes)?;
+            tracing::info!(path = %path.display(), format, "export written");
+        }
+        None => io::stdout().write_all(bytes)?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BundleArgs, Command, DiffArgs, ExportArgs, parse, usage};
+    use crate::rendering::{ExportFormat, ExportLayout, ExportOptions};
+    use crate::services::InxiMode;
+    use crate::services::diff::DiffRules;
+    use crate::services::redaction::RedactionProfile;
+    use std::path::PathBuf;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn parses_commands_and_export_flags() {
+        assert_eq!(parse(args("")).unwrap(), Command::Serve);
+        assert_eq!(
+            parse(args("export --mode basic --redact -o report.md")).unwrap(),
+            Command::Export(ExportArgs {
+                mode: InxiMode::Basic,
+                format: ExportFormat::Markdown,
+                redaction: Some(RedactionProfile::Standard),
+                options: ExportOptions::default(),
+                output: Some(PathBuf::from("report.md")),
+            })
+        );
+        match parse(args("export --format bbcode --no-redact --limit 5000")).unwrap() {
+            Command::Export(export) => {
+                assert_eq!(export.format, ExportFormat::BBCode);
+                assert_eq!(export.redaction, Some(RedactionProfile::None));
+                assert_eq!(export.options.forum_limit, 5000);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        match parse(args("export")).unwrap() {
+            Command::Export(export) => assert!(
+                usage().contains(&format!("Detail level (default: {})", export.mode.as_str()))
+            ),
+            other => panic!("unexpected command {other:?}"),
+        }
+        match parse(args("export --format pdf --exclude Network,Sensors --redaction strict --layout compact --text-appendix")).unwrap() {
+            Command::Export(export) => {
+                assert_eq!(export.options.exclude, ["Network", "Sensors"]);
+                assert_eq!(export.redaction, Some(RedactionProfile::Strict));
+                assert_eq!(export.options.layout, ExportLayout::Compact);
+                assert!(export.options.text_appendix);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert_eq!(
+            parse(args("bundle --redaction strict -o certification.zip")).unwrap(),
+            Command::Bundle(BundleArgs {
+                redaction: RedactionProfile::Strict,
+                output: Some(PathBuf::from("certification.zip")),
+            })
+        );
+        assert_eq!(
+            parse(args(
+                "diff before.json after.json --format json --ignore Graphics:driver --no-volatile"
+            ))
+            .unwrap(),
+            Command::Diff(DiffArgs {
+                old: PathBuf::from("before.json"),
+                new: PathBuf::from("after.json"),
+                markdown: false,
+                rules: DiffRules::new(false, &["Graphics:driver".to_string()]).unwrap(),
+                output: None,
+            })
+        );
+        assert!(parse(args("diff only.json")).is_err());
+        assert!(parse(args("diff a.json b.json --ignore *")).is_err());
+        assert!(parse(args("bundle --format md")).is_err());
+        assert!(parse(args("export --format csv --text-appendix")).is_err());
+        assert!(parse(args("export --limit 10")).is_err());
+        assert!(parse(args("export --format")).is_err());
+        assert!(parse(args("export --mode ultra")).is_err());
+        assert!(parse(args("launch")).is_err());
+    }
+}
@@ -3,14 +3,137 @@
 // Extension guidance: Add new feature flags, alternate addresses, or env-driven overrides here when needed.
 // Security considerations: Avoid embedding secrets here; the values are safe immutable defaults for a local service.
 
+use std::time::Duration;
+
 pub const BIND_ADDR: &str = "127.0.0.1:3050";
 pub const STATIC_PREFIX: &str = "/static";
 pub const STATIC_ROUTE: &str = "/static/{*file}";
+/// Header carrying the per-request ID, accepted from clients and always echoed in responses.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Longest client-supplied request ID that is reused instead of generating a new one.
+pub const REQUEST_ID_MAX_LEN: usize = 64;
+/// Cache policy for fingerprinted asset URLs, whose content never changes.
+pub const ASSET_IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+/// Cache policy for plain asset URLs; browsers revalidate with the ETag.
+pub const ASSET_REVALIDATE_CACHE_CONTROL: &str = "no-cache";
+/// Legacy unversioned alias of `API_V1_SYSTEM_ROUTE`, kept for existing clients.
 pub const API_ROUTE: &str = "/api/system";
+pub const API_V1_SYSTEM_ROUTE: &str = "/api/v1/system";
+pub const API_V1_SECTIONS_ROUTE: &str = "/api/v1/sections";
+pub const API_V1_SECTION_ROUTE: &str = "/api/v1/sections/{title}";
+pub const API_V1_ENTRIES_ROUTE: &str = "/api/v1/entries";
+pub const API_V1_SEARCH_ROUTE: &str = "/api/v1/search";
+pub const API_V1_CARD_SVG_ROUTE: &str = "/api/v1/card.svg";
+pub const API_V1_CARD_PNG_ROUTE: &str = "/api/v1/card.png";
+pub const API_V1_SNAPSHOTS_ROUTE: &str = "/api/v1/snapshots";
+pub const API_V1_SNAPSHOT_ROUTE: &str = "/api/v1/snapshots/{id}";
+pub const API_V1_DIFF_ROUTE: &str = "/api/v1/diff";
+/// Browser sign-in: `GET` reports the caller's role, `POST` exchanges a token for a session cookie, `DELETE` signs out.
+pub const API_V1_SESSION_ROUTE: &str = "/api/v1/session";
+pub const API_V1_MODES_ROUTE: &str = "/api/v1/modes";
+pub const API_V1_HEALTH_ROUTE: &str = "/api/v1/health";
+pub const API_V1_OPENAPI_ROUTE: &str = "/api/v1/openapi.json";
+pub const API_V1_STREAM_ROUTE: &str = "/api/v1/stream";
+pub const API_V1_SCHEMA_ROUTE: &str = "/api/v1/schema/system-report.json";
 pub const DOWNLOAD_ROUTE: &str = "/download";
+/// ZIP of every mode's HTML, JSON, Markdown and inxi text with a checksum manifest.
+pub const DOWNLOAD_BUNDLE_ROUTE: &str = "/download/bundle";
+pub const HEALTHZ_ROUTE: &str = "/healthz";
+pub const METRICS_ROUTE: &str = "/metrics";
+pub const READYZ_ROUTE: &str = "/readyz";
 pub const DASHBOARD_ROUTE: &str = "/";
 pub const DEFAULT_MODE: &str = "basic";
 pub const DOWNLOAD_FILENAME_PREFIX: &str = "inxi-dashboard";
+/// systemd and D-Bus locations of the machine ID, hashed into report metadata.
+pub const MACHINE_ID_PATHS: &[&str] = &["/etc/machine-id", "/var/lib/dbus/machine-id"];
+/// Requests a single client may burst before being throttled.
+pub const RATE_LIMIT_BURST: u32 = 6;
+/// Time for a client to regain one request from its budget.
+pub const RATE_LIMIT_REFILL: Duration = Duration::from_secs(10);
+/// Upper bound on simultaneously running inxi processes.
+pub const MAX_CONCURRENT_INXI: usize = 2;
+/// How long a collected report is reused for the same mode before inxi runs again.
+pub const REPORT_CACHE_TTL: Duration = Duration::from_secs(15);
+/// How long a collected report stays downloadable by its ID (`/download?report={id}`).
+pub const REPORT_RETENTION: Duration = Duration::from_secs(60 * 60);
+/// Most reports held for download by ID; the oldest are dropped first.
+pub const REPORT_RETENTION_MAX: usize = 32;
+/// Directory for persistent data such as snapshots; defaults to `$XDG_DATA_HOME/inxi-dash`.
+pub const DATA_DIR_ENV: &str = "INXI_DASH_DATA_DIR";
+/// Seconds between scheduled snapshots; unset or `0` disables the schedule.
+pub const SNAPSHOT_INTERVAL_ENV: &str = "INXI_DASH_SNAPSHOT_INTERVAL";
+/// Shortest accepted snapshot interval, so a typo cannot keep inxi running back to back.
+pub const SNAPSHOT_MIN_INTERVAL: Duration = Duration::from_secs(60);
+/// Mode collected by scheduled snapshots.
+pub const SNAPSHOT_SCHEDULE_MODE: &str = "full";
+/// Scheduled snapshots kept before the oldest are deleted; manual snapshots are never pruned.
+pub const SNAPSHOT_SCHEDULE_KEEP: usize = 200;
+/// Page size of snapshot listings when `limit` is not given.
+pub const SNAPSHOT_PAGE_DEFAULT: usize = 20;
+/// Largest accepted `limit` for snapshot listings.
+pub const SNAPSHOT_PAGE_MAX: usize = 100;
+/// Longest snapshot label, in characters.
+pub const SNAPSHOT_LABEL_MAX_CHARS: usize = 64;
+/// Longest snapshot note, in characters.
+pub const SNAPSHOT_NOTES_MAX_CHARS: usize = 2_000;
+/// Built-in diff ignore rules for readings that change between any two runs: `[Section:]field` or `Section:*`.
+pub const DIFF_VOLATILE_RULES: &[&str] = &[
+    "Sensors:*",
+    "CPU:avg",
+    "CPU:cores",
+    "CPU:speed",
+    "Network:speed",
+    "Battery:charge",
+    "Battery:status",
+    "temp",
+    "uptime",
+    "processes",
+    "used",
+];
+/// Most extra ignore rules accepted by one diff.
+pub const DIFF_MAX_RULES: usize = 32;
+/// Longest single diff ignore rule, in characters.
+pub const DIFF_RULE_MAX_CHARS: usize = 64;
+/// Mode whose report backs `/metrics`; scrapes reuse the report cache so inxi runs at most once per TTL.
+pub const METRICS_MODE: &str = "full";
+/// How often live streams push a new report; kept above REPORT_CACHE_TTL so each tick sees fresh data.
+pub const STREAM_INTERVAL: Duration = Duration::from_secs(30);
+/// How long a collection waits for a free inxi slot before answering 429.
+pub const INXI_QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long in-flight requests may run after SIGTERM/SIGINT before remaining inxi children are killed.
+pub const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long to wait for killed inxi children to be reaped after the drain timeout.
+pub const SHUTDOWN_KILL_GRACE: Duration = Duration::from_secs(1);
+/// Upper bound on hits returned by one search; `total` still reports the full count.
+pub const SEARCH_MAX_RESULTS: usize = 200;
+/// Longest accepted `q`/`key` search pattern, in bytes.
+pub const SEARCH_MAX_PATTERN_LEN: usize = 256;
+/// Compiled-size cap for opt-in regex searches, keeping pathological patterns cheap to reject.
+pub const SEARCH_REGEX_SIZE_LIMIT: usize = 1 << 20;
+/// Default character budget for forum exports (Discourse's default `max_post_length`).
+pub const FORUM_POST_MAX_CHARS: usize = 32_000;
+/// Smallest `limit` accepted for forum exports; below this not even the summary fits.
+pub const FORUM_POST_MIN_CHARS: usize = 500;
+/// Longest single entry value kept in forum exports before it is shortened with `...`.
+pub const FORUM_VALUE_MAX_CHARS: usize = 400;
+/// Most section or category names accepted in one export `include`/`exclude` list.
+pub const EXPORT_FILTER_MAX_NAMES: usize = 32;
+/// Longest single name in an export `include`/`exclude` list.
+pub const EXPORT_FILTER_NAME_MAX_CHARS: usize = 64;
+/// Longest entry value kept by the compact export layout before it is shortened with `...`.
+pub const EXPORT_COMPACT_VALUE_MAX_CHARS: usize = 160;
+/// Cap on the option tag appended to export filenames (e.g. `only-cpu-gpu-strict-raw`).
+pub const EXPORT_FILENAME_TAG_MAX_CHARS: usize = 80;
+/// Comma-separated `role:token` pairs accepted as `Authorization: Bearer` credentials.
+pub const ACCESS_TOKENS_ENV: &str = "INXI_DASH_TOKENS";
+/// Role granted to requests without a bearer token (viewer, operator, or admin).
+pub const ANONYMOUS_ROLE_ENV: &str = "INXI_DASH_ANONYMOUS_ROLE";
+/// HttpOnly cookie holding a browser session ID, the dashboard's substitute for the `Authorization` header.
+pub const SESSION_COOKIE: &str = "inxi_dash_session";
+/// How long a browser session stays valid after signing in.
+pub const SESSION_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+/// Most browser sessions held at once; the one closest to expiry is dropped first.
+pub const SESSION_MAX: usize = 256;
 
 /// Built-in theme options exposed to the UI without touching rendering logic.
 pub const THEME_SUGGESTIONS: &[(&str, &str)] = &[
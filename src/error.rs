@@ -1,13 +1,14 @@
 // Responsibility: Define centralized error surfaces and HTTP translation for the entire application.
 // Design reasoning: Using a single error enum lets layers bubble issues while preserving context for logging and clients.
 // Extension guidance: Add new variants or HTTP mappings here whenever a new subsystem introduces a failure mode.
-// Security considerations: Avoid leaking sensitive details by returning sanitized messages in HTTP responses.
+// Security considerations: Responses carry a stable code and a sanitized message; stderr, I/O and path details only reach the logs.
 
 use axum::{
     Json,
-    http::StatusCode,
+    http::{HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
 };
+use schemars::JsonSchema;
 use serde::Serialize;
 use thiserror::Error;
 
@@ -19,6 +20,24 @@ pub enum AppError {
     CommandFailure(String),
     #[error("invalid mode requested: {0}")]
     InvalidMode(String),
+    #[error("invalid query: {0}")]
+    InvalidQuery(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("not acceptable: {0}")]
+    NotAcceptable(String),
+    #[error("access denied: {0}")]
+    Forbidden(String),
+    #[error("invalid arguments: {0}")]
+    Usage(String),
+    #[error("invalid configuration: {0}")]
+    Config(String),
+    #[error("server is shutting down")]
+    ShuttingDown,
+    #[error("snapshot storage unavailable: {0}")]
+    StorageUnavailable(String),
+    #[error("too many requests; retry after {retry_after}s")]
+    RateLimited { retry_after: u64 },
     #[error("asset not found: {0}")]
     AssetNotFound(String),
     #[error("failed to parse system report: {0}")]
@@ -27,17 +46,71 @@ pub enum AppError {
     Io(#[from] std::io::Error),
 }
 
-#[derive(Serialize)]
-struct ErrorResponse {
-    message: String,
+#[derive(Serialize, JsonSchema)]
+pub struct ErrorResponse {
+    /// Stable, machine-readable error code such as `rate_limited`.
+    pub code: String,
+    pub message: String,
+    /// Matches the `X-Request-Id` response header and the server log span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+/// Attached to error responses so the request-context middleware can re-render them with the request ID.
+#[derive(Clone, Debug)]
+pub struct ErrorDetails {
+    pub code: &'static str,
+    pub message: String,
 }
 
 impl AppError {
+    /// Stable identifier clients can branch on; never change an existing code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::MissingBinary(_) => "missing_binary",
+            AppError::CommandFailure(_) => "command_failed",
+            AppError::InvalidMode(_) => "invalid_mode",
+            AppError::InvalidQuery(_) => "invalid_query",
+            AppError::NotFound(_) => "not_found",
+            AppError::NotAcceptable(_) => "not_acceptable",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::Usage(_) => "invalid_arguments",
+            AppError::Config(_) => "config_error",
+            AppError::ShuttingDown => "shutting_down",
+            AppError::StorageUnavailable(_) => "storage_unavailable",
+            AppError::RateLimited { .. } => "rate_limited",
+            AppError::AssetNotFound(_) => "asset_not_found",
+            AppError::Parse(_) => "parse_failed",
+            AppError::Io(_) => "internal_error",
+        }
+    }
+
+    /// Client-facing message. Variants wrapping stderr, I/O or configuration detail are replaced by a fixed text.
+    pub fn public_message(&self) -> String {
+        match self {
+            AppError::CommandFailure(_) => "inxi execution failed".to_string(),
+            AppError::Config(_) => "server configuration error".to_string(),
+            AppError::StorageUnavailable(_) => "snapshot storage is unavailable".to_string(),
+            AppError::Parse(_) => "failed to parse system report".to_string(),
+            AppError::Io(_) => "internal server error".to_string(),
+            other => other.to_string(),
+        }
+    }
+
     pub fn status(&self) -> StatusCode {
         match self {
             AppError::MissingBinary(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::CommandFailure(_) => StatusCode::BAD_GATEWAY,
             AppError::InvalidMode(_) => StatusCode::BAD_REQUEST,
+            AppError::InvalidQuery(_) => StatusCode::BAD_REQUEST,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::NotAcceptable(_) => StatusCode::NOT_ACCEPTABLE,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Usage(_) => StatusCode::BAD_REQUEST,
+            AppError::Config(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::ShuttingDown => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::StorageUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
             AppError::AssetNotFound(_) => StatusCode::NOT_FOUND,
             AppError::Parse(_) => StatusCode::UNPROCESSABLE_ENTITY,
             AppError::Io(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -48,13 +121,48 @@ impl AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = self.status();
-        tracing::warn!(error = %self, "handled request error");
-        (
+        let code = self.code();
+        if status.is_server_error() {
+            tracing::error!(code, error = %self, "request failed");
+        } else {
+            tracing::warn!(code, error = %self, "handled request error");
+        }
+        let message = self.public_message();
+        let mut response = (
             status,
             Json(ErrorResponse {
-                message: self.to_string(),
+                code: code.to_string(),
+                message: message.clone(),
+                request_id: None,
             }),
         )
-            .into_response()
+            .into_response();
+        response
+            .extensions_mut()
+            .insert(ErrorDetails { code, message });
+        if let AppError::RateLimited { retry_after } = self {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AppError;
+
+    #[test]
+    fn public_messages_hide_command_and_io_detail() {
+        let failure = AppError::CommandFailure("perl: /root/.inxi: permission denied".to_string());
+        assert_eq!(failure.code(), "command_failed");
+        assert!(!failure.public_message().contains("/root"));
+
+        let io = AppError::Io(std::io::Error::other("disk full at /var/lib"));
+        assert_eq!(io.public_message(), "internal server error");
+
+        let query = AppError::InvalidQuery("'key' parameter is required".to_string());
+        assert_eq!(query.public_message(), query.to_string());
     }
 }
@@ -1,5 +1,5 @@
 // Responsibility: Provide embedded static asset lookup compiled from build.rs.
-// Design reasoning: Matching paths to include_str! keeps the runtime server free of filesystem access.
+// Design reasoning: Hashes and gzip/brotli variants are computed at build time so serving is a table lookup.
 // Extension guidance: Re-run build.rs or add files under static/; new entries appear automatically.
 // Security considerations: Only tracked assets are served, eliminating directory traversal risks.
 pub enum AssetContent {
@@ -7,18 +7,129 @@ pub enum AssetContent {
     Binary(&'static [u8]),
 }
 
-pub fn get_asset(path: &str) -> Option<(&'static str, AssetContent)> {
+pub struct Asset {
+    pub fingerprinted_path: &'static str,
+    pub content_type: &'static str,
+    pub content: AssetContent,
+    pub etag: &'static str,
+    pub gzip: Option<&'static [u8]>,
+    pub brotli: Option<&'static [u8]>,
+}
+
+pub static ASSETS: [Asset; 12] = [
+    Asset {
+        fingerprinted_path: "/static/css/app.3f042af4c289802a.css",
+        content_type: "text/css",
+        content: AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/css/app.css"))),
+        etag: "\"3f042af4c289802a\"",
+        gzip: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/css/app.css.gz"))),
+        brotli: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/css/app.css.br"))),
+    },
+    Asset {
+        fingerprinted_path: "/static/css/melt.199ed7a1a3bc79ac.css",
+        content_type: "text/css",
+        content: AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/css/melt.css"))),
+        etag: "\"199ed7a1a3bc79ac\"",
+        gzip: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/css/melt.css.gz"))),
+        brotli: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/css/melt.css.br"))),
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/chip.79f9704f1bab6384.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/chip.png"))),
+        etag: "\"79f9704f1bab6384\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/graphics-card.66655aaa5c00954f.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/graphics-card.png"))),
+        etag: "\"66655aaa5c00954f\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/keyboard-and-mouse.1f52c5f027960c3a.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/keyboard-and-mouse.png"))),
+        etag: "\"1f52c5f027960c3a\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/mainboard.64013a7c9db0f8d5.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/mainboard.png"))),
+        etag: "\"64013a7c9db0f8d5\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/memory.e153ee9b751d4cbc.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/memory.png"))),
+        etag: "\"e153ee9b751d4cbc\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/monitor.d095cd260c1ec26c.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/monitor.png"))),
+        etag: "\"d095cd260c1ec26c\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/ssd-drive.d0e77a9c7e49f1db.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/ssd-drive.png"))),
+        etag: "\"d0e77a9c7e49f1db\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/icons/ssd.d099670938b18970.png",
+        content_type: "image/png",
+        content: AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/ssd.png"))),
+        etag: "\"d099670938b18970\"",
+        gzip: None,
+        brotli: None,
+    },
+    Asset {
+        fingerprinted_path: "/static/js/dashboard.44d17210db723a84.js",
+        content_type: "application/javascript",
+        content: AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/js/dashboard.js"))),
+        etag: "\"44d17210db723a84\"",
+        gzip: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/js/dashboard.js.gz"))),
+        brotli: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/js/dashboard.js.br"))),
+    },
+    Asset {
+        fingerprinted_path: "/static/js/melt.b249e92cd335af3f.js",
+        content_type: "application/javascript",
+        content: AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/js/melt.js"))),
+        etag: "\"b249e92cd335af3f\"",
+        gzip: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/js/melt.js.gz"))),
+        brotli: Some(include_bytes!(concat!(env!("OUT_DIR"), "/compressed/static/js/melt.js.br"))),
+    },
+];
+
+/// Resolves both the plain and the fingerprinted URL of an asset.
+pub fn get_asset(path: &str) -> Option<&'static Asset> {
     match path {
-        "/static/css/app.css" => Some(("text/css", AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/css/app.css"))))),
-        "/static/css/melt.css" => Some(("text/css", AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/css/melt.css"))))),
-        "/static/icons/chip.png" => Some(("image/png", AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/chip.png"))))),
-        "/static/icons/graphics-card.png" => Some(("image/png", AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/graphics-card.png"))))),
-        "/static/icons/keyboard-and-mouse.png" => Some(("image/png", AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/keyboard-and-mouse.png"))))),
-        "/static/icons/mainboard.png" => Some(("image/png", AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/mainboard.png"))))),
-        "/static/icons/ssd-drive.png" => Some(("image/png", AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/ssd-drive.png"))))),
-        "/static/icons/ssd.png" => Some(("image/png", AssetContent::Binary(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/icons/ssd.png"))))),
-        "/static/js/dashboard.js" => Some(("application/javascript", AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/js/dashboard.js"))))),
-        "/static/js/melt.js" => Some(("application/javascript", AssetContent::Text(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/static/js/melt.js"))))),
+        "/static/css/app.css" | "/static/css/app.3f042af4c289802a.css" => Some(&ASSETS[0]),
+        "/static/css/melt.css" | "/static/css/melt.199ed7a1a3bc79ac.css" => Some(&ASSETS[1]),
+        "/static/icons/chip.png" | "/static/icons/chip.79f9704f1bab6384.png" => Some(&ASSETS[2]),
+        "/static/icons/graphics-card.png" | "/static/icons/graphics-card.66655aaa5c00954f.png" => Some(&ASSETS[3]),
+        "/static/icons/keyboard-and-mouse.png" | "/static/icons/keyboard-and-mouse.1f52c5f027960c3a.png" => Some(&ASSETS[4]),
+        "/static/icons/mainboard.png" | "/static/icons/mainboard.64013a7c9db0f8d5.png" => Some(&ASSETS[5]),
+        "/static/icons/memory.png" | "/static/icons/memory.e153ee9b751d4cbc.png" => Some(&ASSETS[6]),
+        "/static/icons/monitor.png" | "/static/icons/monitor.d095cd260c1ec26c.png" => Some(&ASSETS[7]),
+        "/static/icons/ssd-drive.png" | "/static/icons/ssd-drive.d0e77a9c7e49f1db.png" => Some(&ASSETS[8]),
+        "/static/icons/ssd.png" | "/static/icons/ssd.d099670938b18970.png" => Some(&ASSETS[9]),
+        "/static/js/dashboard.js" | "/static/js/dashboard.44d17210db723a84.js" => Some(&ASSETS[10]),
+        "/static/js/melt.js" | "/static/js/melt.b249e92cd335af3f.js" => Some(&ASSETS[11]),
         _ => None,
     }
 }
@@ -1,8 +1,9 @@
 // Responsibility: Bootstrap the HTTP server, configure observability, and wire routes to services.
 // Design reasoning: Keeping startup logic centralized ensures configuration, dependency checks, and server lifecycle are coherent.
-// Extension guidance: Add metrics, global middleware, or feature flags here while keeping route definitions intact.
+// Extension guidance: Add metrics, global middleware, or feature flags here while keeping route definitions intact; shutdown steps go in `drain`.
 // Security considerations: We verify required binaries early to fail fast rather than later allowing undefined behavior.
 
+mod cli;
 mod config;
 mod error;
 mod generated_assets;
@@ -12,42 +13,162 @@ mod routes;
 mod services;
 mod utils;
 
-use axum::{Extension, Router, routing::get, serve};
-use std::{io, process::Stdio, sync::Arc};
+use axum::{
+    Extension, Router, middleware,
+    routing::{get, post},
+    serve,
+};
+use std::{
+    io,
+    net::SocketAddr,
+    process::Stdio,
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
 use tokio::net::TcpListener;
+use tokio::signal::unix::{SignalKind, signal};
 use tracing_subscriber::EnvFilter;
 
-use crate::config::{API_ROUTE, BIND_ADDR, DASHBOARD_ROUTE, DOWNLOAD_ROUTE, STATIC_ROUTE};
+use crate::cli::Command;
+use crate::config::{
+    API_ROUTE, API_V1_CARD_PNG_ROUTE, API_V1_CARD_SVG_ROUTE, API_V1_DIFF_ROUTE,
+    API_V1_ENTRIES_ROUTE, API_V1_HEALTH_ROUTE, API_V1_MODES_ROUTE, API_V1_OPENAPI_ROUTE,
+    API_V1_SCHEMA_ROUTE, API_V1_SEARCH_ROUTE, API_V1_SECTION_ROUTE, API_V1_SECTIONS_ROUTE,
+    API_V1_SESSION_ROUTE, API_V1_SNAPSHOT_ROUTE, API_V1_SNAPSHOTS_ROUTE, API_V1_STREAM_ROUTE,
+    API_V1_SYSTEM_ROUTE, BIND_ADDR, DASHBOARD_ROUTE, DOWNLOAD_BUNDLE_ROUTE, DOWNLOAD_ROUTE,
+    HEALTHZ_ROUTE, METRICS_ROUTE, RATE_LIMIT_BURST, RATE_LIMIT_REFILL, READYZ_ROUTE,
+    SHUTDOWN_DRAIN_TIMEOUT, SHUTDOWN_KILL_GRACE, STATIC_ROUTE,
+};
 use crate::error::AppError;
-use crate::routes::{api_handler, dashboard_handler, download_handler, static_handler};
-use crate::services::InxiService;
+use crate::routes::{
+    api_handler, bundle_handler, card_png_handler, card_svg_handler, create_snapshot_handler,
+    dashboard_handler, delete_snapshot_handler, diff_handler, download_handler, entries_handler,
+    fallback_handler, health_handler, login_handler, logout_handler, metrics_handler,
+    modes_handler, openapi_handler, readiness_handler, request_context, schema_handler,
+    search_handler, section_handler, sections_handler, session_handler, snapshot_handler,
+    snapshots_handler, static_handler, stream_handler, update_snapshot_handler,
+};
+use crate::services::{AccessPolicy, InxiService, RateLimiter, SnapshotStore, snapshots};
+use crate::utils::strip_ansi;
 
 #[tokio::main]
 async fn main() {
-    if let Err(err) = run().await {
+    let command = cli::parse(std::env::args().skip(1));
+    let filter = EnvFilter::from_default_env().add_directive("inxi_dash=info".parse().unwrap());
+    let logger = tracing_subscriber::fmt().with_env_filter(filter);
+    // CLI commands may write exports to stdout, so their logs go to stderr.
+    match command {
+        Ok(Command::Serve) => logger.init(),
+        _ => logger.with_writer(io::stderr).init(),
+    }
+
+    let result = match command {
+        Ok(Command::Serve) => run().await,
+        Ok(Command::Export(args)) => match ensure_inxi_available() {
+            Ok(_) => cli::export(args).await,
+            Err(err) => Err(err),
+        },
+        Ok(Command::Bundle(args)) => match ensure_inxi_available() {
+            Ok(_) => cli::bundle(args).await,
+            Err(err) => Err(err),
+        },
+        Ok(Command::Diff(args)) => cli::diff(args),
+        Ok(Command::Help) => {
+            println!("{}", cli::usage());
+            Ok(())
+        }
+        Err(err) => {
+            eprintln!("{err}\n\n{}", cli::usage());
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(err) = result {
         tracing::error!(error = %err, "service terminated");
         std::process::exit(1);
     }
 }
 
 async fn run() -> Result<(), AppError> {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::from_default_env().add_directive("inxi_to_html=info".parse().unwrap()),
-        )
-        .init();
-
     tracing::info!(version = env!("CARGO_PKG_VERSION"), "inxi-dash starting");
-    ensure_inxi_available()?;
+    let inxi_version = ensure_inxi_available()?;
 
-    let service = Arc::new(InxiService::new());
+    let service = Arc::new(InxiService::new(Some(inxi_version)));
+    let policy = Arc::new(AccessPolicy::from_env()?);
+    tracing::info!(
+        tokens = policy.token_count(),
+        anonymous_role = %policy.anonymous_role(),
+        "loaded access policy"
+    );
+    let limiter = Arc::new(RateLimiter::new(RATE_LIMIT_BURST, RATE_LIMIT_REFILL));
+    let store = Arc::new(SnapshotStore::from_env());
+    let schedule = snapshots::schedule_interval()?;
+    // A missing or unwritable data directory only disables snapshots; each use retries opening it.
+    match store.blocking(SnapshotStore::count).await {
+        Ok(count) => tracing::info!(
+            dir = ?store.dir(),
+            snapshots = count,
+            schedule_secs = schedule.map(|interval| interval.as_secs()),
+            "opened snapshot store"
+        ),
+        Err(err) => tracing::warn!(
+            error = %err,
+            "snapshot store unavailable; snapshot routes answer 503 until it can be opened"
+        ),
+    }
+    if let Some(interval) = schedule {
+        tokio::spawn(snapshots::run_schedule(
+            Arc::clone(&store),
+            Arc::clone(&service),
+            interval,
+        ));
+    }
 
     let router = Router::new()
         .route(DASHBOARD_ROUTE, get(dashboard_handler))
         .route(API_ROUTE, get(api_handler))
+        .route(API_V1_SYSTEM_ROUTE, get(api_handler))
+        .route(API_V1_SECTIONS_ROUTE, get(sections_handler))
+        .route(API_V1_SECTION_ROUTE, get(section_handler))
+        .route(API_V1_ENTRIES_ROUTE, get(entries_handler))
+        .route(API_V1_MODES_ROUTE, get(modes_handler))
+        .route(API_V1_STREAM_ROUTE, get(stream_handler))
+        .route(API_V1_SEARCH_ROUTE, get(search_handler))
+        .route(API_V1_CARD_SVG_ROUTE, get(card_svg_handler))
+        .route(API_V1_CARD_PNG_ROUTE, get(card_png_handler))
+        .route(
+            API_V1_SNAPSHOTS_ROUTE,
+            get(snapshots_handler).post(create_snapshot_handler),
+        )
+        .route(
+            API_V1_SNAPSHOT_ROUTE,
+            get(snapshot_handler)
+                .patch(update_snapshot_handler)
+                .delete(delete_snapshot_handler),
+        )
+        .route(API_V1_DIFF_ROUTE, post(diff_handler))
+        .route(
+            API_V1_SESSION_ROUTE,
+            get(session_handler)
+                .post(login_handler)
+                .delete(logout_handler),
+        )
+        .route(API_V1_HEALTH_ROUTE, get(health_handler))
+        .route(HEALTHZ_ROUTE, get(health_handler))
+        .route(READYZ_ROUTE, get(readiness_handler))
+        .route(METRICS_ROUTE, get(metrics_handler))
+        .route(API_V1_OPENAPI_ROUTE, get(openapi_handler))
+        .route(API_V1_SCHEMA_ROUTE, get(schema_handler))
         .route(DOWNLOAD_ROUTE, get(download_handler))
+        .route(DOWNLOAD_BUNDLE_ROUTE, get(bundle_handler))
         .route(STATIC_ROUTE, get(static_handler))
-        .layer(Extension(service));
+        .fallback(fallback_handler)
+        .layer(Extension(Arc::clone(&service)))
+        .layer(Extension(policy))
+        .layer(Extension(limiter))
+        .layer(Extension(store))
+        // Outermost, so the request span and error rendering cover every other layer.
+        .layer(middleware::from_fn(request_context));
 
     let listener = TcpListener::bind(BIND_ADDR)
         .await
@@ -55,27 +176,107 @@ async fn run() -> Result<(), AppError> {
 
     let addr = listener
         .local_addr()
-        .map_err(|err| AppError::Io(io::Error::new(io::ErrorKind::Other, err)))?;
+        .map_err(|err| AppError::Io(io::Error::other(err)))?;
 
     tracing::info!(address = %addr, "binding server");
-    serve(listener, router)
-        .await
-        .map_err(|err| AppError::Io(err))?;
+    let signal_service = Arc::clone(&service);
+    let shutdown_started = Arc::new(OnceLock::new());
+    let signal_started = Arc::clone(&shutdown_started);
+    let server = serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        let signal = shutdown_signal().await;
+        let _ = signal_started.set(Instant::now());
+        tracing::info!(
+            signal,
+            running_collections = signal_service.running_collections(),
+            drain_timeout_secs = SHUTDOWN_DRAIN_TIMEOUT.as_secs(),
+            "shutdown signal received; draining requests"
+        );
+        signal_service.begin_shutdown();
+    });
+
+    let drained = tokio::select! {
+        result = async { server.await } => {
+            result.map_err(AppError::Io)?;
+            true
+        }
+        _ = drain(&service) => false,
+    };
+
+    let killed = if drained {
+        0
+    } else {
+        let killed = service.abort_collections();
+        tracing::warn!(
+            killed_collections = killed,
+            "drain timeout elapsed; killing running inxi and closing remaining connections"
+        );
+        // Connection tasks outlive the server future; give them a moment to observe the abort and reap.
+        let deadline = Instant::now() + SHUTDOWN_KILL_GRACE;
+        while service.running_collections() > 0 && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        killed
+    };
+    tracing::info!(
+        drained,
+        killed_collections = killed,
+        elapsed_ms = shutdown_started
+            .get()
+            .map_or(0, |started| started.elapsed().as_millis() as u64),
+        "shutdown complete"
+    );
 
     Ok(())
 }
 
-fn ensure_inxi_available() -> Result<(), AppError> {
-    let status = std::process::Command::new("inxi")
+/// Resolves once shutdown has begun and the drain timeout has elapsed.
+async fn drain(service: &InxiService) {
+    service.shutting_down().await;
+    tokio::time::sleep(SHUTDOWN_DRAIN_TIMEOUT).await;
+}
+
+async fn shutdown_signal() -> &'static str {
+    let mut terminate = match signal(SignalKind::terminate()) {
+        Ok(stream) => Some(stream),
+        Err(err) => {
+            tracing::error!(error = %err, "failed to install SIGTERM handler");
+            None
+        }
+    };
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => "SIGINT",
+        _ = async {
+            match terminate.as_mut() {
+                Some(stream) => {
+                    stream.recv().await;
+                }
+                None => std::future::pending::<()>().await,
+            }
+        } => "SIGTERM",
+    }
+}
+
+fn ensure_inxi_available() -> Result<String, AppError> {
+    let output = std::process::Command::new("inxi")
         .arg("--version")
-        .stdout(Stdio::null())
         .stderr(Stdio::null())
-        .status();
+        .output();
 
-    match status {
-        Ok(state) if state.success() => {
-            tracing::info!("verified inxi binary availability");
-            Ok(())
+    match output {
+        Ok(state) if state.status.success() => {
+            let version = strip_ansi(&String::from_utf8_lossy(&state.stdout))
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or("unknown")
+                .to_string();
+            tracing::info!(version = %version, "verified inxi binary availability");
+            Ok(version)
         }
         Ok(_) => Err(AppError::CommandFailure(
             "inxi --version returned non-zero".to_string(),
@@ -0,0 +1,96 @@
+// Responsibility: Define the auxiliary DTOs exchanged with the versioned /api/v1 endpoints.
+// Design reasoning: Keeping response shapes as typed structs lets the OpenAPI document be derived from the same definitions.
+// Extension guidance: Add new response types here and register them with the OpenAPI builder in services/openapi.rs.
+// Security considerations: These payloads carry only metadata about modes, roles and report structure; tokens are accepted, never echoed.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Title and entry count of one report section, for clients that only need the outline.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SectionSummary {
+    pub title: String,
+    pub entries: usize,
+}
+
+/// One fact located by key, with the section and entry it came from.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct EntryMatch {
+    pub section: String,
+    /// Key of the report entry that contains the match.
+    pub entry: String,
+    /// Matched key: the entry key itself or an inline `label:` inside its value.
+    pub key: String,
+    pub value: String,
+}
+
+/// Result page of `/api/v1/search`.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SearchResults {
+    /// Number of matching entries before truncation.
+    pub total: usize,
+    /// True when `hits` was cut at the server's result limit.
+    pub truncated: bool,
+    pub hits: Vec<SearchHit>,
+}
+
+/// One matching report entry with its section context.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SearchHit {
+    pub section: String,
+    /// Position of the section in the report, to tell apart repeated titles.
+    pub section_index: usize,
+    pub key: String,
+    pub value: String,
+    pub highlights: Vec<Highlight>,
+}
+
+/// A matched span in the hit's key or value, in UTF-16 code units (`end` exclusive).
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct Highlight {
+    pub field: HighlightField,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Serialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightField {
+    Key,
+    Value,
+}
+
+/// Describes an inxi detail level and whether the calling role may request it.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct ModeInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub args: &'static [&'static str],
+    pub allowed: bool,
+}
+
+/// Liveness payload of the API namespace.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct HealthStatus {
+    pub status: &'static str,
+    pub version: &'static str,
+}
+
+/// The caller's role and how it was identified, so clients can hide controls the role cannot use.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SessionInfo {
+    pub role: &'static str,
+    /// `anonymous`, `token` (`Authorization` header) or `session` (cookie from signing in).
+    pub credential: &'static str,
+    /// Whether access tokens are configured, i.e. whether signing in can change the role.
+    pub login_available: bool,
+    /// Whether the role may save, annotate and delete snapshots; every role may list and view them.
+    pub manages_snapshots: bool,
+}
+
+/// Access token exchanged for a browser session cookie.
+#[derive(Deserialize, JsonSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LoginRequest {
+    pub token: String,
+}
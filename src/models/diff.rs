@@ -0,0 +1,103 @@
+// Responsibility: Define the request and result shapes of report diffs (`/api/v1/diff` and `inxi-dash diff`).
+// Design reasoning: Changes are grouped by section with whole old/new values plus the inline fields that differ, so views can show either.
+// Extension guidance: Keep `ReportDiff` additive; the dashboard and Markdown export read it field by field.
+// Security considerations: Diffs only echo the two reports given; the live side is collected with the caller's role restrictions.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::SystemReport;
+
+#[derive(Serialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Modified => "modified",
+        }
+    }
+}
+
+/// Identity of one compared report.
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct DiffSide {
+    pub id: String,
+    pub mode: String,
+    pub hostname: Option<String>,
+    pub collected_utc: String,
+    pub schema_version: String,
+}
+
+/// An inline field (`driver: amdgpu`) that differs inside a modified entry; the entry key names its leading text.
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub name: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct EntryChange {
+    /// Entry key in the newer report (the older one for removed entries).
+    pub key: String,
+    pub kind: ChangeKind,
+    pub old: Option<String>,
+    pub new: Option<String>,
+    /// For modified entries, the fields that differ once ignored fields are removed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+}
+
+/// Changes within one section; `added`/`removed` sections exist in only one report.
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct SectionDiff {
+    pub title: String,
+    pub kind: ChangeKind,
+    pub changes: Vec<EntryChange>,
+}
+
+#[derive(Serialize, JsonSchema, Debug, Clone, Default, PartialEq)]
+pub struct DiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub modified: usize,
+    /// Entries whose only differences were in ignored fields.
+    pub ignored: usize,
+}
+
+/// Result of comparing an older report with a newer one.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct ReportDiff {
+    pub old: DiffSide,
+    pub new: DiffSide,
+    /// Ignore rules applied, as `[Section:]field` or `Section:*`.
+    pub rules: Vec<String>,
+    pub summary: DiffSummary,
+    /// Sections with changes, in the newer report's order followed by removed sections.
+    pub sections: Vec<SectionDiff>,
+}
+
+/// Body of `POST /api/v1/diff`.
+#[derive(Deserialize, JsonSchema, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DiffRequest {
+    /// Baseline report: a `/api/v1/system` response, a JSON export or a snapshot's `report`.
+    pub old: SystemReport,
+    /// Report to compare with; when omitted the live report of `mode` is collected.
+    pub new: Option<SystemReport>,
+    /// Mode of the live report; defaults to the mode of `old`. Only valid without `new`.
+    pub mode: Option<String>,
+    /// Extra ignore rules such as `Graphics:driver` or `Battery:*`.
+    #[serde(default)]
+    pub ignore: Vec<String>,
+    /// Apply the built-in rules for volatile readings (temperatures, speeds, uptime); defaults to true.
+    pub volatile: Option<bool>,
+}
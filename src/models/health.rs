@@ -0,0 +1,61 @@
+// Responsibility: Define the liveness and readiness payloads served to monitors and orchestrators.
+// Design reasoning: Typed status structs keep probe output stable and let the OpenAPI document describe it.
+// Extension guidance: Add fields for new providers or caches as optional members so existing probes keep parsing.
+// Security considerations: Failures carry only the error code and sanitized message; stderr stays in the server log.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Outcome of the most recent successful inxi collection.
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+pub struct CollectionRecord {
+    pub mode: String,
+    /// Unix timestamp (seconds) when the collection finished.
+    pub finished_at: u64,
+    pub duration_ms: u64,
+}
+
+/// Outcome of the most recent failed inxi collection.
+#[derive(Serialize, JsonSchema, Debug, Clone)]
+pub struct CollectionFailure {
+    pub mode: String,
+    /// Unix timestamp (seconds) when the collection failed.
+    pub failed_at: u64,
+    /// Stable error code, as in API error responses.
+    pub code: &'static str,
+    pub error: String,
+}
+
+/// A cached report for one mode.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct CacheState {
+    pub mode: String,
+    pub age_secs: u64,
+    pub fresh: bool,
+}
+
+/// Collection history and cache contents of the inxi service.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct ServiceStatus {
+    pub last_success: Option<CollectionRecord>,
+    pub last_failure: Option<CollectionFailure>,
+    pub cache_ttl_secs: u64,
+    pub cache: Vec<CacheState>,
+}
+
+/// Whether the inxi binary can currently be found, and which version was detected at startup.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct InxiBinaryStatus {
+    pub present: bool,
+    pub path: Option<String>,
+    pub version: Option<String>,
+}
+
+/// Payload of `/readyz`.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct ReadinessReport {
+    /// `ready` when inxi is available, `unavailable` otherwise.
+    pub status: &'static str,
+    pub inxi: InxiBinaryStatus,
+    pub collections: ServiceStatus,
+}
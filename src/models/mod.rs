@@ -3,6 +3,25 @@
 // Extension guidance: Add more report types or DTOs here and export them centrally.
 // Security considerations: Models expose only sanitized fields to downstream layers.
 
+pub mod api;
+pub mod diff;
+pub mod health;
+pub mod snapshot;
 pub mod system_report;
 
-pub use system_report::{SystemEntry, SystemReport, SystemSection};
+pub use api::{
+    EntryMatch, HealthStatus, Highlight, HighlightField, LoginRequest, ModeInfo, SearchHit,
+    SearchResults, SectionSummary, SessionInfo,
+};
+pub use diff::{
+    ChangeKind, DiffRequest, DiffSide, DiffSummary, EntryChange, FieldChange, ReportDiff,
+    SectionDiff,
+};
+pub use health::{
+    CacheState, CollectionFailure, CollectionRecord, InxiBinaryStatus, ReadinessReport,
+    ServiceStatus,
+};
+pub use snapshot::{
+    Snapshot, SnapshotCreate, SnapshotPage, SnapshotSummary, SnapshotTrigger, SnapshotUpdate,
+};
+pub use system_report::{ReportMetadata, SCHEMA_VERSION, SystemEntry, SystemReport, SystemSection};
@@ -0,0 +1,74 @@
+// Responsibility: Define saved snapshots, their listing summaries and the request bodies of /api/v1/snapshots.
+// Design reasoning: A snapshot wraps an unmodified SystemReport, so stored history renders with the same code as live reports.
+// Extension guidance: New annotations go on Snapshot and SnapshotSummary with `#[serde(default)]` so existing files still load.
+// Security considerations: Stored reports are unredacted; routes apply the reader's role before returning them.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::models::SystemReport;
+
+/// What caused a snapshot to be saved.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotTrigger {
+    Manual,
+    Schedule,
+}
+
+/// A saved report with its annotations; the on-disk format and the body of `GET /api/v1/snapshots/{id}`.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct Snapshot {
+    pub id: String,
+    /// Save time as ISO-8601 UTC with milliseconds.
+    pub saved_utc: String,
+    pub trigger: SnapshotTrigger,
+    pub label: Option<String>,
+    pub notes: Option<String>,
+    pub report: SystemReport,
+}
+
+/// One snapshot in a listing: annotations and report facts without the sections.
+#[derive(Serialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct SnapshotSummary {
+    pub id: String,
+    pub saved_utc: String,
+    pub trigger: SnapshotTrigger,
+    pub label: Option<String>,
+    pub notes: Option<String>,
+    pub mode: String,
+    /// ID of the stored report (see `SystemReport.id`).
+    pub report_id: String,
+    pub collected_utc: String,
+    pub sections: usize,
+}
+
+/// One page of `GET /api/v1/snapshots`, newest first.
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct SnapshotPage {
+    /// Snapshots matching the filter across all pages.
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub snapshots: Vec<SnapshotSummary>,
+}
+
+/// Body of `POST /api/v1/snapshots`: save the report with this ID, or collect `mode` now.
+#[derive(Deserialize, JsonSchema, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SnapshotCreate {
+    /// ID of a report the server still holds (e.g. the one on screen); excludes `mode`.
+    pub report: Option<String>,
+    /// Mode to collect when `report` is not given; defaults to basic.
+    pub mode: Option<String>,
+    pub label: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Body of `PATCH /api/v1/snapshots/{id}`; given fields replace the stored ones and empty strings clear them.
+#[derive(Deserialize, JsonSchema, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SnapshotUpdate {
+    pub label: Option<String>,
+    pub notes: Option<String>,
+}
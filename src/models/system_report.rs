@@ -1,24 +1,57 @@
 // Responsibility: Define the DTOs produced by parsing inxi output and served via JSON.
 // Design reasoning: Structuring the report makes serialization predictable and keeps parsing logic testable.
-// Extension guidance: Add precise field types or nested structures when extracting richer metadata.
+// Extension guidance: Add precise field types or nested structures when extracting richer metadata, and bump SCHEMA_VERSION; fields added in a minor version need `#[serde(default)]` so older documents still load.
 // Security considerations: Treat sensitive strings as raw text without executing them or exposing beyond this schema.
 
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+/// Version of the serialized report shape. Minor bumps only add optional fields;
+/// major bumps remove, rename, or retype existing fields.
+pub const SCHEMA_VERSION: &str = "1.2.0";
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct SystemReport {
+    /// Semantic version of this document's shape; see `SCHEMA_VERSION`.
+    pub schema_version: String,
+    /// Random ID of this collection; the server keeps the report downloadable as `/download?report={id}` for a while (added in 1.2.0).
+    #[serde(default)]
+    pub id: String,
     pub timestamp: u64,
     pub mode: String,
+    /// Where and how the report was collected (added in 1.1.0).
+    #[serde(default)]
+    pub metadata: ReportMetadata,
     pub sections: Vec<SystemSection>,
 }
 
-#[derive(Serialize, Debug)]
+/// Provenance of a report, so exports from many machines can be told apart.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq)]
+pub struct ReportMetadata {
+    /// Host name from the System section; `<filter>` once redacted.
+    pub hostname: Option<String>,
+    /// Application-keyed SHA-256 of `/etc/machine-id` (16 hex digits); stable per installation
+    /// without revealing the ID itself. `<filter>` once redacted.
+    pub machine_id_hash: Option<String>,
+    pub kernel: Option<String>,
+    pub distro: Option<String>,
+    pub inxi_version: Option<String>,
+    pub inxi_dash_version: String,
+    /// Wall-clock duration of the inxi run in milliseconds; absent for reports parsed from text.
+    pub collection_ms: Option<u64>,
+    /// Collection time as ISO-8601 UTC, e.g. `2026-10-18T18:53:26Z`.
+    pub collected_utc: String,
+    /// Collection time as ISO-8601 with the collecting host's UTC offset.
+    pub collected_local: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct SystemSection {
     pub title: String,
     pub entries: Vec<SystemEntry>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct SystemEntry {
     pub key: String,
     pub value: String,
@@ -0,0 +1,233 @@
+// Responsibility: Pack one report per inxi mode into a ZIP bundle of HTML, JSON, Markdown and rebuilt text files plus a checksum manifest.
+// Design reasoning: Every file is rendered by the single-format exporters from the same redacted copy, so bundle files match `/download` output.
+// Extension guidance: Add a format to the per-report file list in `bundle_zip`; the manifest and SHA256SUMS pick up every written file.
+// Security considerations: Redaction is applied once per report before any file is rendered; paths are fixed names, never report text.
+
+use std::io::{Cursor, Write};
+
+use chrono::{DateTime, Datelike, Local, SecondsFormat, Timelike, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::error::AppError;
+use crate::models::SystemReport;
+use crate::rendering::formats::inxi_text;
+use crate::rendering::{ExportOptions, download_page, markdown_report};
+use crate::services::InxiMode;
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    generator: String,
+    created_utc: String,
+    redaction: &'static str,
+    /// Modes the caller's role may not request, so the bundle leaves them out.
+    skipped_modes: Vec<&'static str>,
+    /// `report.txt` is rebuilt from the parsed report rather than copied from inxi's output.
+    text_source: &'static str,
+    reports: Vec<ManifestReport<'a>>,
+    files: Vec<ManifestFile>,
+}
+
+#[derive(Serialize)]
+struct ManifestReport<'a> {
+    mode: &'a str,
+    id: &'a str,
+    schema_version: &'a str,
+    collected_utc: &'a str,
+    collection_ms: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct ManifestFile {
+    path: String,
+    mode: String,
+    format: &'static str,
+    bytes: usize,
+    sha256: String,
+}
+
+/// ZIP with `{mode}/report.html|json|md|txt` per report, then `manifest.json` and `SHA256SUMS`.
+pub fn bundle_zip(
+    reports: &[SystemReport],
+    skipped: &[InxiMode],
+    options: &ExportOptions,
+) -> Result<Vec<u8>, AppError> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut files = Vec::new();
+
+    let mut exported = Vec::with_capacity(reports.len());
+    for report in reports {
+        let mut report = report.clone();
+        let metadata = options.apply(&mut report)?;
+        let json =
+            serde_json::to_vec_pretty(&report).map_err(|err| bundle_error(err.to_string()))?;
+        let bodies = [
+            (
+                "report.html",
+                "html",
+                download_page(&report, &metadata)?.into_bytes(),
+            ),
+            ("report.json", "json", json),
+            (
+                "report.md",
+                "md",
+                markdown_report(&report, &metadata).into_bytes(),
+            ),
+            ("report.txt", "text", inxi_text(&report).into_bytes()),
+        ];
+        for (name, format, body) in bodies {
+            let path = format!("{}/{name}", report.mode);
+            add_file(&mut zip, &path, &body, report.timestamp)?;
+            files.push(ManifestFile {
+                path,
+                mode: report.mode.clone(),
+                format,
+                bytes: body.len(),
+                sha256: sha256_hex(&body),
+            });
+        }
+        exported.push(report);
+    }
+
+    let now = Utc::now();
+    let manifest = Manifest {
+        generator: format!("inxi-dash {}", env!("CARGO_PKG_VERSION")),
+        created_utc: now.to_rfc3339_opts(SecondsFormat::Secs, true),
+        redaction: options.redaction.as_str(),
+        skipped_modes: skipped.iter().map(|mode| mode.as_str()).collect(),
+        text_source: "rebuilt from the parsed report, not inxi's original output",
+        reports: exported
+            .iter()
+            .map(|report| ManifestReport {
+                mode: &report.mode,
+                id: &report.id,
+                schema_version: &report.schema_version,
+                collected_utc: &report.metadata.collected_utc,
+                collection_ms: report.metadata.collection_ms,
+            })
+            .collect(),
+        files,
+    };
+    let manifest_json =
+        serde_json::to_vec_pretty(&manifest).map_err(|err| bundle_error(err.to_string()))?;
+    let timestamp = now.timestamp().max(0) as u64;
+    add_file(&mut zip, "manifest.json", &manifest_json, timestamp)?;
+
+    // `sha256sum -c SHA256SUMS` verifies an unpacked bundle, including the manifest itself.
+    let sums = manifest
+        .files
+        .iter()
+        .map(|file| (file.sha256.clone(), file.path.as_str()))
+        .chain([(sha256_hex(&manifest_json), "manifest.json")])
+        .map(|(sha256, path)| format!("{sha256}  {path}\n"))
+        .collect::<String>();
+    add_file(&mut zip, "SHA256SUMS", sums.as_bytes(), timestamp)?;
+
+    zip.finish()
+        .map(Cursor::into_inner)
+        .map_err(|err| bundle_error(err.to_string()))
+}
+
+fn add_file(
+    zip: &mut ZipWriter<Cursor<Vec<u8>>>,
+    path: &str,
+    body: &[u8],
+    timestamp: u64,
+) -> Result<(), AppError> {
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .last_modified_time(zip_time(timestamp))
+        .unix_permissions(0o644);
+    zip.start_file(path, options)
+        .map_err(|err| bundle_error(err.to_string()))?;
+    zip.write_all(body)?;
+    Ok(())
+}
+
+/// ZIP entries carry local wall-clock times without a zone, as `unzip` and file managers expect.
+fn zip_time(timestamp: u64) -> zip::DateTime {
+    DateTime::<Utc>::from_timestamp(timestamp as i64, 0)
+        .map(|time| time.with_timezone(&Local))
+        .and_then(|time| {
+            zip::DateTime::from_date_and_time(
+                u16::try_from(time.year()).ok()?,
+                time.month() as u8,
+                time.day() as u8,
+                time.hour() as u8,
+                time.minute() as u8,
+                time.second() as u8,
+            )
+            .ok()
+        })
+        .unwrap_or_default()
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn bundle_error(message: String) -> AppError {
+    AppError::Io(std::io::Error::other(format!(
+        "bundle rendering failed: {message}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bundle_zip;
+    use crate::rendering::ExportOptions;
+    use crate::services::redaction::RedactionProfile;
+    use crate::services::{InxiMode, parser::parse_system_report};
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn packs_every_mode_with_a_verifiable_manifest() {
+        let reports = [InxiMode::Basic, InxiMode::Full]
+            .into_iter()
+            .map(|mode| {
+                parse_system_report("System:\n  Host: box Kernel: 6.12.68\n", mode).expect("report")
+            })
+            .collect::<Vec<_>>();
+        let options = ExportOptions {
+            redaction: RedactionProfile::Standard,
+            ..ExportOptions::default()
+        };
+        let bytes = bundle_zip(&reports, &[InxiMode::Maximum], &options).expect("bundle");
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).expect("zip");
+        assert_eq!(archive.len(), 10);
+        let mut text = String::new();
+        archive
+            .by_name("full/report.txt")
+            .expect("text")
+            .read_to_string(&mut text)
+            .expect("read");
+        assert_eq!(text, "System:\n  Host: <filter> Kernel: 6.12.68\n");
+
+        let mut manifest = String::new();
+        archive
+            .by_name("manifest.json")
+            .expect("manifest")
+            .read_to_string(&mut manifest)
+            .expect("read");
+        let manifest: serde_json::Value = serde_json::from_str(&manifest).expect("json");
+        assert_eq!(manifest["redaction"], "standard");
+        assert_eq!(manifest["skipped_modes"], serde_json::json!(["maximum"]));
+        assert_eq!(manifest["files"].as_array().map(Vec::len), Some(8));
+        assert_eq!(manifest["reports"][1]["id"], reports[1].id.as_str());
+
+        let mut sums = String::new();
+        archive
+            .by_name("SHA256SUMS")
+            .expect("sums")
+            .read_to_string(&mut sums)
+            .expect("read");
+        assert_eq!(sums.lines().count(), 9);
+        assert!(sums.ends_with("  manifest.json\n"));
+    }
+}
@@ -0,0 +1,217 @@
+// Responsibility: Render a neofetch-style spec card (host, distro, kernel, CPU, GPU, memory, storage) as SVG and rasterize it to PNG.
+// Design reasoning: The SVG is the single layout; PNG output is resvg rendering that same document, so both images always match.
+// Extension guidance: Add rows via `lookup::key_facts` and map their icons in `ROW_ICONS`; colours come from `theme::PALETTES`.
+// Security considerations: Text is XML-escaped, icons are embedded data URLs, and the rasterizer never resolves external hrefs.
+
+use std::io;
+use std::sync::{Arc, OnceLock};
+
+use resvg::{tiny_skia, usvg};
+
+use crate::error::AppError;
+use crate::models::SystemReport;
+use crate::rendering::html_renderer::asset_data_url;
+use crate::rendering::theme::Palette;
+use crate::services::lookup;
+
+const CARD_WIDTH: u32 = 640;
+const HEADER_HEIGHT: u32 = 96;
+const ROW_HEIGHT: u32 = 44;
+const VALUE_MAX_CHARS: usize = 56;
+/// PNGs are drawn at twice the SVG size so they stay sharp on high-DPI screens.
+const PNG_SCALE: f32 = 2.0;
+const FONT_FAMILY: &str = "DejaVu Sans, Noto Sans, Liberation Sans, Arial, sans-serif";
+
+/// Icons from `static/icons`, matching the dashboard cards for the same facts.
+const ROW_ICONS: &[(&str, &str)] = &[
+    ("Distro", "/static/icons/monitor.png"),
+    ("Kernel", "/static/icons/monitor.png"),
+    ("CPU", "/static/icons/chip.png"),
+    ("GPU", "/static/icons/graphics-card.png"),
+    ("Memory", "/static/icons/memory.png"),
+    ("Storage", "/static/icons/ssd-drive.png"),
+];
+const HOST_ICON: &str = "/static/icons/mainboard.png";
+const IDENTITY_MATRIX: &str = "1 0 0 0 0 0 1 0 0 0 0 0 1 0 0 0 0 0 1 0";
+const INVERT_MATRIX: &str = "-1 0 0 0 1 0 -1 0 0 1 0 0 -1 0 1 0 0 0 1 0";
+
+pub fn card_svg(report: &SystemReport, palette: &Palette) -> String {
+    let facts = lookup::key_facts(report);
+    let hostname = lookup::hostname(report).unwrap_or_else(|| "Linux system".to_string());
+    let height = HEADER_HEIGHT + ROW_HEIGHT * facts.len().max(1) as u32 + 24;
+
+    let rows = facts
+        .iter()
+        .enumerate()
+        .map(|(index, (label, value))| {
+            let top = HEADER_HEIGHT + ROW_HEIGHT * index as u32;
+            let icon = ROW_ICONS
+                .iter()
+                .find(|(name, _)| name == label)
+                .map(|(_, path)| icon_image(path, 32, top + 8, 24))
+                .unwrap_or_default();
+            format!(
+                r#"  <line x1="28" y1="{top}" x2="{right}" y2="{top}" stroke="{border}" />
+  {icon}
+  <text x="68" y="{baseline}" font-size="13" font-weight="bold" fill="{primary}">{label}</text>
+  <text x="160" y="{baseline}" font-size="14" fill="{text}">{value}</text>
+"#,
+                right = CARD_WIDTH - 28,
+                border = palette.border,
+                baseline = top + 26,
+                primary = palette.primary,
+                text = palette.text,
+                label = escape_xml(label),
+                value = escape_xml(&truncate(value, VALUE_MAX_CHARS)),
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="{font}">
+  <defs>
+    <linearGradient id="background" x1="0" y1="0" x2="1" y2="1">
+      <stop offset="0" stop-color="{bg_start}" />
+      <stop offset="1" stop-color="{bg_end}" />
+    </linearGradient>
+    <filter id="icon"><feColorMatrix type="matrix" values="{icon_matrix}" /></filter>
+    <clipPath id="card"><rect x="0.5" y="0.5" width="{inner_width}" height="{inner_height}" rx="16" /></clipPath>
+  </defs>
+  <rect x="0.5" y="0.5" width="{inner_width}" height="{inner_height}" rx="16" fill="url(#background)" stroke="{border}" />
+  <rect x="0" y="0" width="8" height="{height}" fill="{primary}" clip-path="url(#card)" />
+  <rect x="20" y="20" width="56" height="56" rx="12" fill="{surface}" stroke="{border}" />
+  {host_icon}
+  <text x="92" y="46" font-size="22" font-weight="bold" fill="{text}">{hostname}</text>
+  <text x="92" y="70" font-size="13" fill="{muted}">inxi-dash · {mode} report</text>
+{rows}</svg>
+"#,
+        width = CARD_WIDTH,
+        height = height,
+        inner_width = CARD_WIDTH - 1,
+        inner_height = height - 1,
+        font = FONT_FAMILY,
+        icon_matrix = if palette.dark {
+            INVERT_MATRIX
+        } else {
+            IDENTITY_MATRIX
+        },
+        bg_start = palette.background.0,
+        bg_end = palette.background.1,
+        border = palette.border,
+        primary = palette.primary,
+        surface = palette.surface,
+        host_icon = icon_image(HOST_ICON, 30, 30, 36),
+        text = palette.text,
+        muted = palette.text_muted,
+        hostname = escape_xml(&truncate(&hostname, 36)),
+        mode = escape_xml(&report.mode),
+        rows = rows,
+    )
+}
+
+/// Rasterizes a card produced by `card_svg`; text uses the host's fonts (DejaVu, Noto or Liberation).
+pub fn card_png(svg: &str) -> Result<Vec<u8>, AppError> {
+    let mut options = usvg::Options {
+        fontdb: Arc::clone(system_fonts()),
+        ..usvg::Options::default()
+    };
+    // Only the embedded data-URL icons may load; file paths and URLs are ignored.
+    options.image_href_resolver.resolve_string = Box::new(|_, _| None);
+
+    let tree = usvg::Tree::from_str(svg, &options).map_err(render_error)?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(PNG_SCALE)
+        .ok_or_else(|| render_error("card size out of range"))?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| render_error("card size out of range"))?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(PNG_SCALE, PNG_SCALE),
+        &mut pixmap.as_mut(),
+    );
+    pixmap.encode_png().map_err(render_error)
+}
+
+/// System fonts are scanned once per process; the scan takes far longer than rendering a card.
+fn system_fonts() -> &'static Arc<usvg::fontdb::Database> {
+    static FONTS: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+    FONTS.get_or_init(|| {
+        let mut database = usvg::fontdb::Database::new();
+        database.load_system_fonts();
+        if database.is_empty() {
+            tracing::warn!("no system fonts found; PNG spec cards will render without text");
+        }
+        Arc::new(database)
+    })
+}
+
+fn icon_image(path: &str, x: u32, y: u32, size: u32) -> String {
+    asset_data_url(path)
+        .map(|href| {
+            format!(
+                r#"<image x="{x}" y="{y}" width="{size}" height="{size}" href="{href}" filter="url(#icon)" />"#
+            )
+        })
+        .unwrap_or_default()
+}
+
+fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() <= max {
+        return value.to_string();
+    }
+    let mut shortened = value.chars().take(max - 1).collect::<String>();
+    shortened.push('…');
+    shortened
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0 documents.
+            ch if ch.is_control() => escaped.push(' '),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn render_error(error: impl std::fmt::Display) -> AppError {
+    AppError::Io(io::Error::other(format!(
+        "spec card rendering failed: {error}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ROW_ICONS, card_png, card_svg};
+    use crate::rendering::html_renderer::asset_data_url;
+    use crate::rendering::theme::palette;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn renders_escaped_svg_and_matching_png() {
+        let raw = "System:\n  Host: <filter> Kernel: 6.12.68 arch: x86_64\n  Desktop: KDE Distro: Arch & Friends\nGraphics:\n  Device-1: AMD Picasso driver: amdgpu\n";
+        let report = parse_system_report(raw, InxiMode::Basic).expect("report");
+        let svg = card_svg(&report, palette("dark").expect("palette"));
+
+        assert!(svg.contains("&lt;filter&gt;"));
+        assert!(svg.contains("Arch &amp; Friends"));
+        assert!(svg.contains("fill=\"#60a5fa\""));
+        assert!(svg.contains("href=\"data:image/png;base64,"));
+
+        let png = card_png(&svg).expect("png");
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        for (_, icon) in ROW_ICONS {
+            assert!(asset_data_url(icon).is_some(), "{icon} is not embedded");
+        }
+    }
+}
@@ -0,0 +1,552 @@
+// Responsibility: Map `/download` and CLI export formats to their renderers, file extensions and media types.
+// Design reasoning: One enum drives the download handler, the CLI and the dashboard's format picker so they never disagree.
+// Extension guidance: Add a variant, its name/extension/media type, and a `render` arm; new per-download options belong in `ExportOptions::apply`.
+// Security considerations: `apply` drops the sections the caller's role hides before any renderer runs; format names come from a fixed allowlist.
+
+use crate::config::{
+    DOWNLOAD_FILENAME_PREFIX, EXPORT_COMPACT_VALUE_MAX_CHARS, EXPORT_FILENAME_TAG_MAX_CHARS,
+    EXPORT_FILTER_MAX_NAMES, EXPORT_FILTER_NAME_MAX_CHARS, FORUM_POST_MAX_CHARS,
+    FORUM_POST_MIN_CHARS,
+};
+use crate::error::AppError;
+use crate::models::SystemReport;
+use crate::rendering::formats::inxi_text;
+use crate::rendering::html_renderer::categorize_sections;
+use crate::rendering::{
+    download_page, forum_bbcode, forum_text, inventory_csv, inventory_xlsx, markdown_report,
+    pdf_report,
+};
+use crate::services::Role;
+use crate::services::redaction::RedactionProfile;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Html,
+    Markdown,
+    Pdf,
+    Text,
+    BBCode,
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 7] = [
+        ExportFormat::Html,
+        ExportFormat::Markdown,
+        ExportFormat::Pdf,
+        ExportFormat::Text,
+        ExportFormat::BBCode,
+        ExportFormat::Csv,
+        ExportFormat::Xlsx,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Text => "text",
+            ExportFormat::BBCode => "bbcode",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "HTML page",
+            ExportFormat::Markdown => "Markdown (issue trackers)",
+            ExportFormat::Pdf => "PDF document",
+            ExportFormat::Text => "Plain text (forums)",
+            ExportFormat::BBCode => "BBCode (forums)",
+            ExportFormat::Csv => "CSV (spreadsheets)",
+            ExportFormat::Xlsx => "Excel workbook",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Text => "txt",
+            ExportFormat::BBCode => "bbcode.txt",
+            other => other.as_str(),
+        }
+    }
+
+    /// Forum formats end up in public posts, so they mask identifying fields unless the caller opts out.
+    pub fn redacts_by_default(self) -> bool {
+        matches!(self, ExportFormat::Text | ExportFormat::BBCode)
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "text/html; charset=utf-8",
+            ExportFormat::Markdown => "text/markdown; charset=utf-8",
+            ExportFormat::Pdf => "application/pdf",
+            ExportFormat::Text | ExportFormat::BBCode => "text/plain; charset=utf-8",
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "html" => Ok(ExportFormat::Html),
+            "md" | "markdown" => Ok(ExportFormat::Markdown),
+            "pdf" => Ok(ExportFormat::Pdf),
+            "text" | "txt" => Ok(ExportFormat::Text),
+            "bbcode" => Ok(ExportFormat::BBCode),
+            "csv" => Ok(ExportFormat::Csv),
+            "xlsx" => Ok(ExportFormat::Xlsx),
+            other => Err(AppError::InvalidQuery(format!(
+                "unknown export format '{other}'; expected one of {}",
+                ExportFormat::ALL.map(ExportFormat::as_str).join(", ")
+            ))),
+        }
+    }
+
+    /// Formats that can carry the inxi-style text appendix.
+    pub fn supports_text_appendix(self) -> bool {
+        matches!(
+            self,
+            ExportFormat::Html | ExportFormat::Markdown | ExportFormat::Pdf | ExportFormat::Xlsx
+        )
+    }
+
+    /// Applies `options` to a copy of `report` and renders the result with its metadata block.
+    pub fn render(
+        self,
+        report: &SystemReport,
+        options: &ExportOptions,
+    ) -> Result<Vec<u8>, AppError> {
+        options.validate(self)?;
+        let mut report = report.clone();
+        let metadata = options.apply(&mut report)?;
+        let report = &report;
+        match self {
+            ExportFormat::Html => download_page(report, &metadata).map(String::into_bytes),
+            ExportFormat::Markdown => Ok(markdown_report(report, &metadata).into_bytes()),
+            ExportFormat::Pdf => pdf_report(report, &metadata),
+            ExportFormat::Text => {
+                Ok(forum_text(report, options.forum_limit, &metadata).into_bytes())
+            }
+            ExportFormat::BBCode => {
+                Ok(forum_bbcode(report, options.forum_limit, &metadata).into_bytes())
+            }
+            ExportFormat::Csv => Ok(inventory_csv(report, &metadata).into_bytes()),
+            ExportFormat::Xlsx => inventory_xlsx(report, &metadata),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportLayout {
+    /// Values as collected.
+    #[default]
+    Detailed,
+    /// Whitespace collapsed and long values shortened.
+    Compact,
+}
+
+impl ExportLayout {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExportLayout::Detailed => "detailed",
+            ExportLayout::Compact => "compact",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "detailed" => Ok(ExportLayout::Detailed),
+            "compact" => Ok(ExportLayout::Compact),
+            other => Err(AppError::InvalidQuery(format!(
+                "unknown layout '{other}'; expected detailed or compact"
+            ))),
+        }
+    }
+}
+
+/// Per-export settings shared by `/download` and the CLI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Character budget for forum formats; other formats ignore it.
+    pub forum_limit: usize,
+    /// Section titles or category labels (case-insensitive) to keep; empty keeps every section.
+    pub include: Vec<String>,
+    /// Section titles or category labels to drop, applied after `include`.
+    pub exclude: Vec<String>,
+    /// Masking applied by the export itself; callers resolve role limits before setting it.
+    pub redaction: RedactionProfile,
+    pub layout: ExportLayout,
+    /// Append the exported sections as inxi-style text. It is rebuilt from the filtered, masked
+    /// report rather than taken from inxi's output, so nothing the export leaves out reappears there.
+    pub text_appendix: bool,
+    /// Caller whose hidden sections are dropped and listed as omitted; `None` (the CLI) keeps them all.
+    pub role: Option<Role>,
+}
+
+impl ExportOptions {
+    pub fn new(forum_limit: Option<usize>) -> Result<Self, AppError> {
+        let forum_limit = forum_limit.unwrap_or(FORUM_POST_MAX_CHARS);
+        if forum_limit < FORUM_POST_MIN_CHARS {
+            return Err(AppError::InvalidQuery(format!(
+                "limit must be at least {FORUM_POST_MIN_CHARS} characters"
+            )));
+        }
+        Ok(Self {
+            forum_limit,
+            ..Self::default()
+        })
+    }
+
+    /// Rejects combinations the format cannot honour, before any inxi run.
+    pub fn validate(&self, format: ExportFormat) -> Result<(), AppError> {
+        if self.text_appendix && !format.supports_text_appendix() {
+            return Err(AppError::InvalidQuery(format!(
+                "the text appendix is not available for format '{}'",
+                format.as_str()
+            )));
+        }
+        if let Some(name) = self.include.iter().find(|name| {
+            self.exclude
+                .iter()
+                .any(|excluded| excluded.eq_ignore_ascii_case(name))
+        }) {
+            return Err(AppError::InvalidQuery(format!(
+                "'{name}' is both included and excluded"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Filters sections, masks values and applies the layout, returning the metadata block describing the result.
+    pub fn apply(&self, report: &mut SystemReport) -> Result<ExportMetadata, AppError> {
+        let categories = categorize_sections(&report.sections);
+        let section_categories = report
+            .sections
+            .iter()
+            .map(|section| {
+                categories
+                    .iter()
+                    .find(|category| {
+                        category
+                            .sections
+                            .iter()
+                            .any(|candidate| std::ptr::eq(*candidate, section))
+                    })
+                    .map_or("", |category| category.label)
+            })
+            .collect::<Vec<_>>();
+        let names_section = |name: &String, index: usize| {
+            name.eq_ignore_ascii_case(&report.sections[index].title)
+                || name.eq_ignore_ascii_case(section_categories[index])
+        };
+        // Names are checked against every collected section, so one the role hides still counts as a match.
+        let unmatched = self
+            .include
+            .iter()
+            .chain(&self.exclude)
+            .filter(|name| !(0..report.sections.len()).any(|index| names_section(name, index)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let keep = (0..report.sections.len())
+            .map(|index| {
+                let named = |names: &[String]| names.iter().any(|name| names_section(name, index));
+                (self.include.is_empty() || named(&self.include)) && !named(&self.exclude)
+            })
+            .collect::<Vec<_>>();
+
+        let mut omitted = Vec::new();
+        let mut withheld = Vec::new();
+        let mut keep = keep.into_iter();
+        report.sections.retain(|section| {
+            let kept = keep.next().unwrap_or(true);
+            let allowed = self
+                .role
+                .is_none_or(|role| role.allows_section(&section.title));
+            let list = if kept { &mut withheld } else { &mut omitted };
+            if (!kept || !allowed) && !list.contains(&section.title) {
+                list.push(section.title.clone());
+            }
+            kept && allowed
+        });
+        if let Some(role) = self.role {
+            omitted.extend(
+                withheld
+                    .iter()
+                    .map(|title| format!("{title} (hidden from the {role} role)")),
+            );
+        }
+        if report.sections.is_empty() && !omitted.is_empty() {
+            return Err(AppError::InvalidQuery(
+                "the include/exclude filters leave no sections to export".to_string(),
+            ));
+        }
+
+        self.redaction.apply(report);
+        // The appendix keeps full values, so it is taken before the compact layout shortens them.
+        let appendix_text = self.text_appendix.then(|| inxi_text(report));
+        if self.layout == ExportLayout::Compact {
+            for entry in report
+                .sections
+                .iter_mut()
+                .flat_map(|section| section.entries.iter_mut())
+            {
+                entry.value = compact_value(&entry.value);
+            }
+        }
+
+        let sections = match (self.include.is_empty(), self.exclude.is_empty()) {
+            (true, true) => "all".to_string(),
+            (false, true) => format!("only {}", self.include.join(", ")),
+            (true, false) => format!("all except {}", self.exclude.join(", ")),
+            (false, false) => format!(
+                "only {}, except {}",
+                self.include.join(", "),
+                self.exclude.join(", ")
+            ),
+        };
+        let omitted = if omitted.is_empty() {
+            "none".to_string()
+        } else {
+            omitted.join(", ")
+        };
+        let mut rows = vec![
+            ("Sections", sections),
+            ("Omitted", omitted),
+            ("Redaction", self.redaction.description().to_string()),
+            ("Layout", self.layout.as_str().to_string()),
+            (
+                "Text appendix",
+                if self.text_appendix {
+                    "included (rebuilt from the exported sections)"
+                } else {
+                    "not included"
+                }
+                .to_string(),
+            ),
+        ];
+        if !unmatched.is_empty() {
+            rows.push(("Unmatched filters", unmatched.join(", ")));
+        }
+        Ok(ExportMetadata {
+            rows,
+            appendix_text,
+        })
+    }
+
+    /// Download filename such as `inxi-dashboard-box-2026-10-18-full-strict.md`: host and local
+    /// collection date identify the machine, then mode and option tag; masked exports leave the host out.
+    pub fn filename(&self, report: &SystemReport, format: ExportFormat) -> String {
+        format!(
+            "{}.{}",
+            self.file_stem(report, &report.mode),
+            format.extension()
+        )
+    }
+
+    /// Filename of an all-modes ZIP bundle, e.g. `inxi-dashboard-box-2026-10-18-bundle.zip`.
+    pub fn bundle_filename(&self, report: &SystemReport) -> String {
+        format!("{}.zip", self.file_stem(report, "bundle"))
+    }
+
+    fn file_stem(&self, report: &SystemReport, kind: &str) -> String {
+        let metadata = &report.metadata;
+        let host = metadata
+            .hostname
+            .as_deref()
+            .filter(|_| !self.redaction.masks())
+            .map(slug)
+            .filter(|host| !host.is_empty());
+        let date = metadata.collected_local.get(..10);
+        let tag = self.filename_tag();
+        let parts = [DOWNLOAD_FILENAME_PREFIX]
+            .into_iter()
+            .chain(host.as_deref())
+            .chain(date)
+            .chain([kind])
+            .chain(Some(tag.as_str()).filter(|tag| !tag.is_empty()));
+        parts.collect::<Vec<_>>().join("-")
+    }
+
+    /// Non-default options as a filename fragment, e.g. `only-cpu-gpu-no-network-strict-text`.
+    pub fn filename_tag(&self) -> String {
+        let names = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| slug(name))
+                .collect::<Vec<_>>()
+                .join("-")
+        };
+        let mut parts = Vec::new();
+        if !self.include.is_empty() {
+            parts.push(format!("only-{}", names(&self.include)));
+        }
+        if !self.exclude.is_empty() {
+            parts.push(format!("no-{}", names(&self.exclude)));
+        }
+        match self.redaction {
+            RedactionProfile::None => {}
+            RedactionProfile::Standard => parts.push("redacted".to_string()),
+            RedactionProfile::Strict => parts.push("strict".to_string()),
+        }
+        if self.layout == ExportLayout::Compact {
+            parts.push("compact".to_string());
+        }
+        if self.text_appendix {
+            parts.push("text".to_string());
+        }
+        let tag = parts.join("-");
+        tag.chars()
+            .take(EXPORT_FILENAME_TAG_MAX_CHARS)
+            .collect::<String>()
+            .trim_end_matches('-')
+            .to_string()
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            forum_limit: FORUM_POST_MAX_CHARS,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            redaction: RedactionProfile::None,
+            layout: ExportLayout::Detailed,
+            text_appendix: false,
+            role: None,
+        }
+    }
+}
+
+/// What an export contains and leaves out, printed by each renderer as its metadata block.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExportMetadata {
+    pub rows: Vec<(&'static str, String)>,
+    /// inxi-style text rebuilt from the filtered, redacted report when the appendix was requested.
+    pub appendix_text: Option<String>,
+}
+
+/// Parses a comma-separated `include`/`exclude` list of section titles or category labels.
+pub fn parse_section_names(value: &str) -> Result<Vec<String>, AppError> {
+    let names = value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if names.len() > EXPORT_FILTER_MAX_NAMES {
+        return Err(AppError::InvalidQuery(format!(
+            "at most {EXPORT_FILTER_MAX_NAMES} section names may be given"
+        )));
+    }
+    if let Some(name) = names.iter().find(|name| {
+        name.chars().count() > EXPORT_FILTER_NAME_MAX_CHARS
+            || !name
+                .chars()
+                .all(|ch| ch.is_alphanumeric() || matches!(ch, ' ' | '-' | '_'))
+    }) {
+        return Err(AppError::InvalidQuery(format!(
+            "invalid section name '{name}'; use letters, digits, spaces, '-' or '_' (up to {EXPORT_FILTER_NAME_MAX_CHARS} characters)"
+        )));
+    }
+    Ok(names)
+}
+
+fn compact_value(value: &str) -> String {
+    let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= EXPORT_COMPACT_VALUE_MAX_CHARS {
+        return collapsed;
+    }
+    let mut shortened = collapsed
+        .chars()
+        .take(EXPORT_COMPACT_VALUE_MAX_CHARS - 3)
+        .collect::<String>();
+    shortened.push_str("...");
+    shortened
+}
+
+fn slug(name: &str) -> String {
+    name.to_lowercase()
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportFormat, ExportLayout, ExportOptions, parse_section_names};
+    use crate::services::redaction::RedactionProfile;
+    use crate::services::{InxiMode, Role, parser::parse_system_report};
+
+    #[test]
+    fn filters_sections_and_records_the_omissions() {
+        let raw = "System:\n  Host: box Kernel: 6.12.68\nCPU:\n  Info: quad core model: AMD Ryzen 5\nNetwork:\n  IF: enp3s0 mac: 00:11:22:33:44:55\n";
+        let mut report = parse_system_report(raw, InxiMode::Basic).expect("report");
+        let options = ExportOptions {
+            exclude: parse_section_names("network, ").expect("names"),
+            redaction: RedactionProfile::Standard,
+            layout: ExportLayout::Compact,
+            text_appendix: true,
+            ..ExportOptions::default()
+        };
+
+        let mut viewer_report = report.clone();
+        let metadata = options.apply(&mut report).expect("apply");
+        let titles = report.sections.iter().map(|section| section.title.as_str());
+        assert_eq!(titles.collect::<Vec<_>>(), ["System", "CPU"]);
+        assert!(metadata.rows.contains(&("Omitted", "Network".to_string())));
+        assert!(
+            !metadata
+                .rows
+                .iter()
+                .any(|(label, _)| *label == "Unmatched filters")
+        );
+
+        // Sections the role hides are listed too, and filter names that match nothing are reported.
+        let viewer = ExportOptions {
+            exclude: vec!["Netwrk".to_string()],
+            role: Some(Role::Viewer),
+            ..ExportOptions::default()
+        };
+        let hidden = viewer.apply(&mut viewer_report).expect("apply");
+        assert_eq!(viewer_report.sections.len(), 2);
+        assert!(hidden.rows.contains(&(
+            "Omitted",
+            "Network (hidden from the viewer role)".to_string()
+        )));
+        assert!(
+            hidden
+                .rows
+                .contains(&("Unmatched filters", "Netwrk".to_string()))
+        );
+        assert!(
+            metadata
+                .appendix_text
+                .expect("appendix")
+                .contains("Host: <filter>")
+        );
+        assert_eq!(options.filename_tag(), "no-network-redacted-compact-text");
+        report.metadata.hostname = Some("Box.lan".to_string());
+        report.metadata.collected_local = "2026-10-18T20:53:00+02:00".to_string();
+        assert_eq!(
+            ExportOptions::default().filename(&report, ExportFormat::Markdown),
+            "inxi-dashboard-box-lan-2026-10-18-basic.md"
+        );
+        assert_eq!(
+            options.filename(&report, ExportFormat::Pdf),
+            "inxi-dashboard-2026-10-18-basic-no-network-redacted-compact-text.pdf"
+        );
+
+        let only_missing = ExportOptions {
+            include: vec!["Battery".to_string()],
+            ..ExportOptions::default()
+        };
+        assert!(only_missing.apply(&mut report).is_err());
+        assert!(parse_section_names("CPU;drop").is_err());
+    }
+}
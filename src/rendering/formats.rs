@@ -0,0 +1,251 @@
+// Responsibility: Serialize a SystemReport into the alternative formats served by the report endpoint (YAML, TOML, CSV, inxi-style text).
+// Design reasoning: Format selection and rendering live together so the handler only negotiates once and writes bytes.
+// Extension guidance: Add a ReportFormat variant, its names/media types, and a render arm; negotiation picks it up automatically.
+// Security considerations: Every format renders the already-restricted report; CSV cells are quoted so values cannot break rows.
+
+use std::io;
+
+use crate::error::AppError;
+use crate::models::SystemReport;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Yaml,
+    Toml,
+    Csv,
+    Text,
+}
+
+impl ReportFormat {
+    pub const ALL: [ReportFormat; 5] = [
+        ReportFormat::Json,
+        ReportFormat::Yaml,
+        ReportFormat::Toml,
+        ReportFormat::Csv,
+        ReportFormat::Text,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Yaml => "yaml",
+            ReportFormat::Toml => "toml",
+            ReportFormat::Csv => "csv",
+            ReportFormat::Text => "text",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ReportFormat::Json => "application/json",
+            ReportFormat::Yaml => "application/yaml",
+            ReportFormat::Toml => "application/toml",
+            ReportFormat::Csv => "text/csv; charset=utf-8",
+            ReportFormat::Text => "text/plain; charset=utf-8",
+        }
+    }
+
+    /// Parses a `format=` query value.
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(ReportFormat::Json),
+            "yaml" | "yml" => Ok(ReportFormat::Yaml),
+            "toml" => Ok(ReportFormat::Toml),
+            "csv" => Ok(ReportFormat::Csv),
+            "text" | "txt" | "plain" => Ok(ReportFormat::Text),
+            other => Err(AppError::InvalidQuery(format!(
+                "unknown format '{other}'; expected one of json, yaml, toml, csv, text"
+            ))),
+        }
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type.to_ascii_lowercase().as_str() {
+            "application/json" | "application/*" | "*/*" => Some(ReportFormat::Json),
+            "application/yaml" | "application/x-yaml" | "text/yaml" | "text/x-yaml" => {
+                Some(ReportFormat::Yaml)
+            }
+            "application/toml" => Some(ReportFormat::Toml),
+            "text/csv" => Some(ReportFormat::Csv),
+            "text/plain" | "text/*" => Some(ReportFormat::Text),
+            _ => None,
+        }
+    }
+
+    /// Picks the format from an explicit `format=` value, else the `Accept` header, else JSON.
+    pub fn negotiate(format: Option<&str>, accept: Option<&str>) -> Result<Self, AppError> {
+        if let Some(format) = format {
+            return Self::parse(format);
+        }
+        let Some(accept) = accept.filter(|value| !value.trim().is_empty()) else {
+            return Ok(ReportFormat::Json);
+        };
+
+        let mut ranges = accept
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let media_type = parts.next().filter(|value| !value.is_empty())?;
+                let quality = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .and_then(|quality| quality.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((media_type, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect::<Vec<_>>();
+        // Stable sort keeps header order for equal weights.
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranges
+            .into_iter()
+            .find_map(|(media_type, _)| Self::from_media_type(media_type))
+            .ok_or_else(|| {
+                AppError::NotAcceptable(format!("no supported report format in Accept: {accept}"))
+            })
+    }
+
+    pub fn render(self, report: &SystemReport) -> Result<String, AppError> {
+        match self {
+            ReportFormat::Json => serde_json::to_string(report).map_err(encode_error),
+            ReportFormat::Yaml => serde_yaml::to_string(report).map_err(encode_error),
+            ReportFormat::Toml => toml::to_string(report).map_err(encode_error),
+            ReportFormat::Csv => Ok(csv_rows(report)),
+            ReportFormat::Text => Ok(inxi_text(report)),
+        }
+    }
+}
+
+fn encode_error(err: impl std::error::Error + Send + Sync + 'static) -> AppError {
+    AppError::Io(io::Error::other(err))
+}
+
+/// One `section,key,value` row per entry, RFC 4180 quoted.
+pub fn csv_rows(report: &SystemReport) -> String {
+    let mut output = String::from("section,key,value\r\n");
+    for section in &report.sections {
+        for entry in &section.entries {
+            output.push_str(&csv_field(&section.title));
+            output.push(',');
+            output.push_str(&csv_field(&entry.key));
+            output.push(',');
+            output.push_str(&csv_field(&entry.value));
+            output.push_str("\r\n");
+        }
+    }
+    output
+}
+
+pub(crate) fn csv_field(value: &str) -> String {
+    // A leading formula character would be evaluated by spreadsheet apps; prefix it so the cell stays text.
+    // Tab and carriage return count too, as spreadsheets strip them before evaluating the cell.
+    // Signed numbers such as `-3` or `+1.5` are plain values, so they are left alone.
+    let signed_number =
+        value.starts_with(['+', '-']) && value[1..].parse::<f64>().is_ok_and(f64::is_finite);
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) && !signed_number {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\r', '\n']) || value.starts_with('\'') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Reconstructs inxi's uncoloured layout: a `Title:` line followed by indented `Key: value` lines.
+pub fn inxi_text(report: &SystemReport) -> String {
+    let mut output = String::new();
+    for section in &report.sections {
+        output.push_str(&section.title);
+        output.push_str(":\n");
+        for entry in &section.entries {
+            output.push_str("  ");
+            output.push_str(&entry.key);
+            output.push_str(": ");
+            output.push_str(&entry.value);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReportFormat, csv_field, csv_rows, inxi_text};
+    use crate::models::{ReportMetadata, SCHEMA_VERSION, SystemEntry, SystemReport, SystemSection};
+
+    fn report() -> SystemReport {
+        SystemReport {
+            schema_version: SCHEMA_VERSION.to_string(),
+            id: "00000000-0000-4000-8000-000000000000".to_string(),
+            timestamp: 1,
+            mode: "basic".to_string(),
+            metadata: ReportMetadata::default(),
+            sections: vec![SystemSection {
+                title: "System".to_string(),
+                entries: vec![SystemEntry {
+                    key: "Host".to_string(),
+                    value: "box Kernel: 6.1, \"lts\"".to_string(),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn negotiates_query_over_accept_header() {
+        let negotiate = ReportFormat::negotiate;
+        assert_eq!(negotiate(None, None).unwrap(), ReportFormat::Json);
+        assert_eq!(
+            negotiate(Some("yml"), Some("text/csv")).unwrap(),
+            ReportFormat::Yaml
+        );
+        assert_eq!(
+            negotiate(None, Some("text/html, text/csv;q=0.9, */*;q=0.1")).unwrap(),
+            ReportFormat::Csv
+        );
+        assert_eq!(
+            negotiate(None, Some("text/html,*/*;q=0.8")).unwrap(),
+            ReportFormat::Json
+        );
+        assert!(negotiate(None, Some("image/png")).is_err());
+        assert!(negotiate(Some("xml"), None).is_err());
+    }
+
+    #[test]
+    fn escapes_formulas_but_not_signed_numbers() {
+        assert_eq!(csv_field("-3"), "-3");
+        assert_eq!(csv_field("-1.5"), "-1.5");
+        assert_eq!(csv_field("+2"), "+2");
+        assert_eq!(csv_field("--"), "\"'--\"");
+        assert_eq!(
+            csv_field("-1+cmd|' /C calc'!A0"),
+            "\"'-1+cmd|' /C calc'!A0\""
+        );
+        assert_eq!(csv_field("=SUM(A1)"), "\"'=SUM(A1)\"");
+        assert_eq!(csv_field("-inf"), "\"'-inf\"");
+        assert_eq!(csv_field("\t=1+1"), "\"'\t=1+1\"");
+        assert_eq!(csv_field("\r=1+1"), "\"'\r=1+1\"");
+    }
+
+    #[test]
+    fn renders_text_formats() {
+        let report = report();
+        assert_eq!(
+            csv_rows(&report),
+            "section,key,value\r\nSystem,Host,\"box Kernel: 6.1, \"\"lts\"\"\"\r\n"
+        );
+        assert_eq!(
+            inxi_text(&report),
+            "System:\n  Host: box Kernel: 6.1, \"lts\"\n"
+        );
+        assert!(
+            ReportFormat::Toml
+                .render(&report)
+                .unwrap()
+                .contains("[[sections]]")
+        );
+    }
+}
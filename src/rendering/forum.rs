@@ -0,0 +1,195 @@
+// Responsibility: Render a SystemReport as compact inxi-style text and as BBCode for pasting into forum posts.
+// Design reasoning: Forums cap post length, so entries are added in report order until the budget runs out and a note records what was trimmed.
+// Extension guidance: New markup dialects wrap `compact_text`; keep the budget arithmetic in characters, as forums count them.
+// Security considerations: Exports default to redacted output; every `[/` inside values is defused so text cannot escape the code block.
+
+use crate::config::FORUM_VALUE_MAX_CHARS;
+use crate::models::SystemReport;
+use crate::rendering::ExportMetadata;
+use crate::services::lookup;
+
+/// inxi-style `Section:` / `  Key: value` lines, as pasted from `inxi -Fxz`, trimmed to `limit` characters.
+pub fn forum_text(report: &SystemReport, limit: usize, metadata: &ExportMetadata) -> String {
+    let header = report_note(report);
+    let note = export_note(metadata);
+    let body = compact_text(
+        report,
+        limit.saturating_sub(char_len(&header) + char_len(&note)),
+    );
+    format!("{header}{body}{note}")
+}
+
+/// A bold one-line summary followed by the compact text inside `[code]` tags (phpBB, FluxBB and Discourse all render it).
+pub fn forum_bbcode(report: &SystemReport, limit: usize, metadata: &ExportMetadata) -> String {
+    let facts = lookup::key_facts(report)
+        .iter()
+        .map(|(label, value)| format!("{label}: {value}"))
+        .collect::<Vec<_>>()
+        .join(" · ");
+    let mut summary = format!("[b]System information[/b] ({})", report.mode);
+    if !facts.is_empty() {
+        summary.push_str(": ");
+        summary.push_str(&facts.replace('[', "(").replace(']', ")"));
+    }
+
+    let open = format!("{summary}\n[code]\n{}", report_note(report));
+    let close = "[/code]\n";
+    let note = export_note(metadata);
+    let budget = limit.saturating_sub(char_len(&open) + char_len(&note) + char_len(close));
+    let body = compact_text(report, budget);
+    format!("{open}{body}{note}{close}")
+}
+
+/// Report metadata as one `Report: ...` line, so posts from several machines can be told apart.
+fn report_note(report: &SystemReport) -> String {
+    let fields = lookup::provenance(report)
+        .iter()
+        .map(|(label, value)| format!("{label}: {value}"))
+        .collect::<Vec<_>>()
+        .join("; ");
+    code_safe(&format!("Report: {fields}\n"))
+}
+
+/// The export metadata block as one `Export: ...` line, so readers of a post know what was left out.
+fn export_note(metadata: &ExportMetadata) -> String {
+    if metadata.rows.is_empty() {
+        return String::new();
+    }
+    let fields = metadata
+        .rows
+        .iter()
+        .map(|(label, value)| format!("{label}: {value}"))
+        .collect::<Vec<_>>()
+        .join("; ");
+    code_safe(&format!("Export: {fields}\n"))
+}
+
+fn compact_text(report: &SystemReport, budget: usize) -> String {
+    let total = report
+        .sections
+        .iter()
+        .map(|section| section.entries.len())
+        .sum::<usize>();
+    let mut output = String::new();
+    let mut used = 0;
+    let mut kept = 0;
+    let mut sections_trimmed = 0;
+    let mut full = false;
+
+    for section in &report.sections {
+        let header = code_safe(&format!("{}:\n", section.title));
+        let mut header_written = false;
+        let mut section_complete = true;
+
+        for entry in &section.entries {
+            let line = code_safe(&format!(
+                "  {}: {}\n",
+                entry.key,
+                compact_value(&entry.value)
+            ));
+            let mut cost = char_len(&line);
+            if !header_written {
+                cost += char_len(&header);
+            }
+            // Keep room for the trim note unless this is the very last entry.
+            let reserve = if kept + 1 == total {
+                0
+            } else {
+                trim_note_len(total)
+            };
+            if full || used + cost + reserve > budget {
+                full = true;
+                section_complete = false;
+                break;
+            }
+            if !header_written {
+                output.push_str(&header);
+                header_written = true;
+            }
+            output.push_str(&line);
+            used += cost;
+            kept += 1;
+        }
+
+        if !section_complete {
+            sections_trimmed += 1;
+        }
+    }
+
+    if kept < total {
+        output.push_str(&trim_note(total - kept, sections_trimmed));
+    }
+    output
+}
+
+/// Breaks up every closing tag, whatever its case, so report text cannot end the surrounding `[code]` block.
+fn code_safe(text: &str) -> String {
+    text.replace("[/", "[ /")
+}
+
+/// Collapses inxi's column padding to single spaces and shortens runaway values such as long flag lists.
+fn compact_value(value: &str) -> String {
+    let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if char_len(&collapsed) <= FORUM_VALUE_MAX_CHARS {
+        return collapsed;
+    }
+    let mut shortened = collapsed
+        .chars()
+        .take(FORUM_VALUE_MAX_CHARS - 3)
+        .collect::<String>();
+    shortened.push_str("...");
+    shortened
+}
+
+fn trim_note(entries: usize, sections: usize) -> String {
+    format!("[trimmed to fit the post limit: {entries} entries from {sections} sections omitted]\n")
+}
+
+/// Upper bound on the trim note's length for a report with `total` entries.
+fn trim_note_len(total: usize) -> usize {
+    char_len(&trim_note(total, total))
+}
+
+fn char_len(text: &str) -> usize {
+    text.chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{forum_bbcode, forum_text};
+    use crate::rendering::ExportMetadata;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    const SAMPLE: &str = "System:\n  Host: box Kernel: 6.12.68 arch: x86_64\n  Desktop: KDE   Distro: Arch [/code] Linux\nDrives:\n  ID-1: /dev/nvme0n1 size: 476 GiB model: [/CODE]\n  ID-2: /dev/sda size: 1 TiB model: [/Code]\n";
+
+    #[test]
+    fn trims_to_the_limit_and_keeps_code_blocks_closed() {
+        let report = parse_system_report(SAMPLE, InxiMode::Basic).expect("report");
+
+        let metadata = ExportMetadata {
+            rows: vec![("Omitted", "Network".to_string())],
+            appendix_text: None,
+        };
+        let text = forum_text(&report, 10_000, &metadata);
+        assert!(text.starts_with("Report: Host: box; inxi-dash: "));
+        assert!(text.contains("\nSystem:\n  Host: box Kernel: 6.12.68 arch: x86_64\n"));
+        assert!(!text.contains("trimmed"));
+        assert!(text.ends_with("\nExport: Omitted: Network\n"));
+
+        let bbcode = forum_bbcode(&report, 10_000, &metadata);
+        assert!(
+            bbcode.starts_with("[b]System information[/b] (basic): Distro: Arch (/code) Linux")
+        );
+        assert_eq!(bbcode.to_lowercase().matches("[/code]").count(), 1);
+        assert!(bbcode.contains("model: [ /CODE]") && bbcode.contains("model: [ /Code]"));
+        assert!(bbcode.ends_with("[/code]\n"));
+
+        let short = forum_bbcode(&report, 380, &ExportMetadata::default());
+        assert!(short.chars().count() <= 380, "{short}");
+        assert!(
+            short.contains("3 entries from 2 sections omitted"),
+            "{short}"
+        );
+        assert!(short.ends_with("[/code]\n"));
+    }
+}
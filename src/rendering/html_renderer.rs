@@ -3,11 +3,22 @@
 // Extension guidance: Add helper builders for new UI sections or alternative layouts without touching routing.
 // Security considerations: This module escapes dynamic values before embedding them in the HTML to prevent injection.
 
-use crate::config::THEME_SUGGESTIONS;
+use std::collections::BTreeMap;
+
+use axum::http::StatusCode;
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::config::{
+    DASHBOARD_ROUTE, DEFAULT_MODE, DOWNLOAD_ROUTE, SNAPSHOT_LABEL_MAX_CHARS,
+    SNAPSHOT_NOTES_MAX_CHARS, THEME_SUGGESTIONS,
+};
 use crate::error::AppError;
 use crate::generated_assets;
 use crate::models::{SystemReport, SystemSection};
 use crate::rendering::theme::THEME_OPTIONS;
+use crate::rendering::{ExportFormat, ExportMetadata};
+use crate::services::lookup;
+use crate::services::schema::system_report_schema;
 
 const MODE_OPTIONS: &[(&str, &str)] = &[
     ("basic", "Basic"),
@@ -77,6 +88,23 @@ const CATEGORY_CONFIG: &[CategoryConfig] = &[
     },
 ];
 
+/// Icons referenced by the dashboard cards; exports inline them so the page works without the server.
+const OFFLINE_ICONS: &[&str] = &[
+    "/static/icons/chip.png",
+    "/static/icons/graphics-card.png",
+    "/static/icons/keyboard-and-mouse.png",
+    "/static/icons/mainboard.png",
+    "/static/icons/memory.png",
+    "/static/icons/monitor.png",
+    "/static/icons/ssd-drive.png",
+];
+
+/// Makes serialized JSON safe to place inside a `<script>` element.
+/// `<` only occurs inside JSON strings, where `\u003c` is an equivalent escape that cannot open a tag.
+fn escape_script_json(value: &str) -> String {
+    value.replace('<', "\\u003c")
+}
+
 fn escape_html(value: &str) -> String {
     let mut escaped = String::with_capacity(value.len());
     for ch in value.chars() {
@@ -92,31 +120,45 @@ fn escape_html(value: &str) -> String {
     escaped
 }
 
-pub fn dashboard_page() -> String {
-    let theme_options = THEME_OPTIONS
+fn theme_options() -> String {
+    THEME_OPTIONS
         .iter()
         .map(|(value, label)| format!("<option value=\"{value}\">{label}</option>"))
         .collect::<Vec<_>>()
-        .join("");
+        .join("")
+}
 
+pub fn dashboard_page() -> String {
     let mode_options = MODE_OPTIONS
         .iter()
         .map(|(value, label)| format!("<option value=\"{value}\">{label}</option>"))
         .collect::<Vec<_>>()
         .join("");
 
+    let export_options = ExportFormat::ALL
+        .iter()
+        .map(|format| {
+            format!(
+                "<option value=\"{value}\">{label}</option>",
+                value = format.as_str(),
+                label = format.label()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
     format!(
         r##"<!doctype html>
-<html lang="en">
+<html lang="en" theme="default">
 <head>
   <meta charset="utf-8" />
   <meta name="viewport" content="width=device-width, initial-scale=1" />
   <title>Inxi System Dashboard</title>
   <link rel="preconnect" href="/" />
-  <link rel="stylesheet" href="/static/css/melt.css" />
-  <link rel="stylesheet" href="/static/css/app.css" />
+  <link rel="stylesheet" href="{melt_css}" />
+  <link rel="stylesheet" href="{app_css}" />
 </head>
-<body theme="default">
+<body>
   <app class="app-shell">
     <header class="backdrop-blur">
       <div class="container flex items-center justify-between">
@@ -126,6 +168,14 @@ pub fn dashboard_page() -> String {
           </div>
           <h1 class="text-xl font-bold m-0 hide-sm">Inxi Dashboard</h1>
         </div>
+        <div class="flex items-center gap-2">
+          <span id="access-role" class="text-xs font-semibold px-2 py-1 rounded bg-primary-light text-primary" hidden></span>
+          <form id="login-form" class="flex items-center gap-2" hidden>
+            <input id="login-token" class="form-item form-item-sm" type="password" autocomplete="current-password" placeholder="Access token" aria-label="Access token" required />
+            <button class="btn btn-primary btn-sm" type="submit">Sign in</button>
+          </form>
+          <button id="logout-button" class="btn btn-outline btn-sm" type="button" hidden>Sign out</button>
+        </div>
       </div>
     </header>
 
@@ -150,20 +200,41 @@ pub fn dashboard_page() -> String {
               <select id="mode-select" class="form-item form-item-sm">{mode_options}</select>
             </div>
 
+            <div class="form-group">
+              <label class="form-label text-xs" for="export-format">Export Format</label>
+              <select id="export-format" class="form-item form-item-sm">{export_options}</select>
+            </div>
+
             <div class="form-group">
               <label class="form-label text-xs">Visual Theme</label>
               <select id="theme-select" class="form-item form-item-sm">{theme_options}</select>
             </div>
 
+            <form id="search-form" class="form-group" role="search">
+              <label class="form-label text-xs" for="search-input">Search Report</label>
+              <input id="search-input" type="search" class="form-item form-item-sm" placeholder="nvme, key:driver, section:Graphics" />
+            </form>
+
+            <div class="form-group">
+              <label class="form-label text-xs" for="live-toggle">
+                <input id="live-toggle" type="checkbox" class="mr-2">Live updates
+              </label>
+            </div>
+
             <div class="mt-4 flex flex-col gap-2">
               <button id="refresh-button" class="btn btn-primary w-100" type="button">
                 <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="mr-2"><path d="M23 4v6h-6"></path><path d="M1 20v-6h6"></path><path d="M3.51 9a9 9 0 0 1 14.85-3.36L23 10M1 14l4.64 4.36A9 9 0 0 0 20.49 15"></path></svg>
                 Refresh Data
               </button>
-              <a id="download-link" class="btn btn-outline w-100" href="/download?mode=maximum">
+              <a id="download-link" class="btn btn-outline w-100" href="{download_route}?mode={default_mode}">
                 <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="mr-2"><path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v4"></path><polyline points="7 10 12 15 17 10"></polyline><line x1="12" y1="15" x2="12" y2="3"></line></svg>
                 Download Snapshot
               </a>
+              <button id="forum-copy" class="btn btn-outline w-100" type="button" title="Redacted BBCode, trimmed to fit a forum post">
+                <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="mr-2"><rect x="9" y="9" width="13" height="13" rx="2" ry="2"></rect><path d="M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1"></path></svg>
+                Copy for Forum
+                <span class="copy-hint text-success font-bold ml-2">✓</span>
+              </button>
             </div>
 
             <div class="mt-4 pt-4 border-t border-dashed">
@@ -173,7 +244,40 @@ pub fn dashboard_page() -> String {
           </div>
         </aside>
 
-        <section class="component-grid" id="component-cards" aria-label="System components"></section>
+        <div>
+          <section id="search-results" class="card p-3 mb-3" aria-live="polite" hidden></section>
+          <section class="component-grid" id="component-cards" aria-label="System components"></section>
+          <section id="snapshot-history" class="card p-3 mt-4" aria-labelledby="snapshot-history-title">
+            <div class="flex items-center justify-between mb-3">
+              <h3 id="snapshot-history-title" class="text-sm font-bold uppercase tracking-wider text-muted m-0">Snapshot History</h3>
+              <span id="snapshot-total" class="text-xs text-muted"></span>
+            </div>
+            <form id="snapshot-form" class="flex flex-wrap gap-2 mb-3" hidden>
+              <input id="snapshot-label" class="form-item form-item-sm" maxlength="{label_max}" placeholder="Label, e.g. before kernel upgrade" />
+              <input id="snapshot-notes" class="form-item form-item-sm" maxlength="{notes_max}" placeholder="Notes" />
+              <button class="btn btn-primary btn-sm" type="submit">Save Snapshot</button>
+            </form>
+            <table class="report-table">
+              <thead><tr><th>Saved</th><th>Label</th><th>Mode</th><th>Trigger</th><th></th></tr></thead>
+              <tbody id="snapshot-rows"></tbody>
+            </table>
+            <div class="flex justify-between mt-3">
+              <button id="snapshot-newer" class="btn btn-outline btn-sm" type="button" disabled>Newer</button>
+              <button id="snapshot-older" class="btn btn-outline btn-sm" type="button" disabled>Older</button>
+            </div>
+          </section>
+          <section id="diff-view" class="card p-3 mt-4" aria-labelledby="diff-title" hidden>
+            <div class="flex items-center justify-between gap-2 mb-2">
+              <h3 id="diff-title" class="text-sm font-bold uppercase tracking-wider text-muted m-0">Changes</h3>
+              <div class="flex gap-2">
+                <button id="diff-markdown" class="btn btn-outline btn-sm" type="button">Download Markdown</button>
+                <button id="diff-close" class="btn btn-outline btn-sm" type="button">Close</button>
+              </div>
+            </div>
+            <p id="diff-summary" class="text-sm text-muted mb-3"></p>
+            <div id="diff-sections"></div>
+          </section>
+        </div>
       </div>
     </main>
 
@@ -183,12 +287,21 @@ pub fn dashboard_page() -> String {
       <p class="m-0 text-xs opacity-50">Theme defaults: {themes}.</p>
     </footer>
   </app>
-  <script src="/static/js/melt.js"></script>
-  <script src="/static/js/dashboard.js"></script>
+  <script src="{melt_js}"></script>
+  <script src="{dashboard_js}"></script>
 </body>
 </html>"##,
+        download_route = DOWNLOAD_ROUTE,
+        default_mode = DEFAULT_MODE,
+        label_max = SNAPSHOT_LABEL_MAX_CHARS,
+        notes_max = SNAPSHOT_NOTES_MAX_CHARS,
+        melt_css = asset_url("/static/css/melt.css"),
+        app_css = asset_url("/static/css/app.css"),
+        melt_js = asset_url("/static/js/melt.js"),
+        dashboard_js = asset_url("/static/js/dashboard.js"),
         mode_options = mode_options,
-        theme_options = theme_options,
+        export_options = export_options,
+        theme_options = theme_options(),
         themes = THEME_SUGGESTIONS
             .iter()
             .map(|(_, label)| *label)
@@ -197,7 +310,45 @@ pub fn dashboard_page() -> String {
     )
 }
 
-pub fn download_page(report: &SystemReport) -> Result<String, AppError> {
+/// Themed page for errors on browser routes; the stored dashboard theme is applied before first paint.
+pub fn error_page(status: StatusCode, code: &str, message: &str, request_id: &str) -> String {
+    format!(
+        r##"<!doctype html>
+<html lang="en" theme="default">
+<head>
+  <meta charset="utf-8" />
+  <meta name="viewport" content="width=device-width, initial-scale=1" />
+  <title>{status_code} {reason} · Inxi Dashboard</title>
+  <link rel="stylesheet" href="{melt_css}" />
+  <link rel="stylesheet" href="{app_css}" />
+  <script>document.documentElement.setAttribute("theme", localStorage.getItem("inxi-dashboard-theme") || "default");</script>
+</head>
+<body>
+  <app class="app-shell">
+    <main class="container py-8">
+      <div class="card p-4 max-w-lg">
+        <p class="text-xs font-bold uppercase tracking-wider text-muted mb-2">Error {status_code} · <code>{code}</code></p>
+        <h2 class="font-bold mb-2">{reason}</h2>
+        <p class="text-muted mb-4">{message}</p>
+        <a class="btn btn-primary" href="{home}">Back to dashboard</a>
+        <p class="mt-4 mb-0 text-xs text-muted">Request ID: <code>{request_id}</code></p>
+      </div>
+    </main>
+  </app>
+</body>
+</html>"##,
+        status_code = status.as_u16(),
+        reason = status.canonical_reason().unwrap_or("Error"),
+        code = escape_html(code),
+        message = escape_html(message),
+        request_id = escape_html(request_id),
+        home = DASHBOARD_ROUTE,
+        melt_css = asset_url("/static/css/melt.css"),
+        app_css = asset_url("/static/css/app.css"),
+    )
+}
+
+pub fn download_page(report: &SystemReport, metadata: &ExportMetadata) -> Result<String, AppError> {
     let css = asset_content("/static/css/melt.css")?;
     let app_css = asset_content("/static/css/app.css")?;
     let js = asset_content("/static/js/dashboard.js")?;
@@ -272,12 +423,46 @@ pub fn download_page(report: &SystemReport) -> Result<String, AppError> {
         .collect::<Vec<_>>()
         .join("");
 
-    let timestamp = report.timestamp;
-    let when = format!("UTC {timestamp}");
+    let when = escape_html(&report.metadata.collected_local);
+    let schema = escape_script_json(&system_report_schema().to_string());
+    let data = escape_script_json(&serde_json::to_string(report).map_err(export_error)?);
+    let icons = OFFLINE_ICONS
+        .iter()
+        .filter_map(|path| Some((*path, asset_data_url(path)?)))
+        .collect::<BTreeMap<_, _>>();
+    let icons = escape_script_json(&serde_json::to_string(&icons).map_err(export_error)?);
+    let mode = escape_html(&report.mode);
+    let table_rows = |rows: &[(&str, String)]| {
+        rows.iter()
+            .map(|(label, value)| {
+                format!(
+                    "<tr><td class=\"font-semibold text-muted\">{label}</td><td class=\"entry-value\">{value}</td></tr>",
+                    value = escape_html(value)
+                )
+            })
+            .collect::<String>()
+    };
+    let mut report_rows = lookup::key_facts(report);
+    report_rows.extend(lookup::provenance(report));
+    let report_rows = table_rows(&report_rows);
+    let metadata_rows = table_rows(&metadata.rows);
+    let text_appendix = metadata
+        .appendix_text
+        .as_deref()
+        .map(|text| {
+            format!(
+                r#"<details id="text-appendix" class="card p-3 mt-4">
+        <summary class="font-bold">inxi-style text (rebuilt from the exported sections)</summary>
+        <pre class="text-xs mt-3 mb-0">{}</pre>
+      </details>"#,
+                escape_html(text)
+            )
+        })
+        .unwrap_or_default();
 
     Ok(format!(
         r##"<!doctype html>
-<html lang="en">
+<html lang="en" theme="default">
 <head>
   <meta charset="utf-8" />
   <meta name="viewport" content="width=device-width, initial-scale=1" />
@@ -287,7 +472,7 @@ pub fn download_page(report: &SystemReport) -> Result<String, AppError> {
 .last-mb-0:last-child {{ margin-bottom: 0 !important; }}
 </style>
 </head>
-<body theme="default">
+<body>
   <app class="app-shell">
     <header class="backdrop-blur">
       <div class="container flex items-center justify-between">
@@ -302,15 +487,61 @@ pub fn download_page(report: &SystemReport) -> Result<String, AppError> {
       <section class="hero mb-4 text-center">
         <h2 class="font-bold mb-2">Hardware Configuration Export</h2>
         <p class="text-muted">Generated on {when}</p>
+        <div id="status-text" class="status-badge inline-flex items-center px-3 py-1 rounded-full text-xs font-semibold bg-primary-light text-primary" hidden></div>
+      </section>
+
+      <section id="report-metadata" class="card p-3 mb-4">
+        <h3 class="text-xs font-bold uppercase tracking-wider text-muted mb-2">Report</h3>
+        <table class="report-table"><tbody>{report_rows}</tbody></table>
+      </section>
+
+      <section id="export-metadata" class="card p-3 mb-4">
+        <h3 class="text-xs font-bold uppercase tracking-wider text-muted mb-2">Export</h3>
+        <table class="report-table"><tbody>{metadata_rows}</tbody></table>
       </section>
 
+      <div id="offline-dashboard" class="grid grid-md-1 gap-3 items-start mb-4" style="grid-template-columns: 280px 1fr;" hidden>
+        <aside>
+          <div class="card p-3 position-sticky" style="top: 80px;">
+            <h3 class="text-sm font-bold uppercase tracking-wider text-muted mb-3">Controls</h3>
+
+            <div class="form-group">
+              <label class="form-label text-xs" for="mode-select">Detail Level</label>
+              <select id="mode-select" class="form-item form-item-sm" disabled><option value="{mode}">{mode}</option></select>
+            </div>
+
+            <div class="form-group">
+              <label class="form-label text-xs" for="theme-select">Visual Theme</label>
+              <select id="theme-select" class="form-item form-item-sm">{theme_options}</select>
+            </div>
+
+            <form id="search-form" class="form-group" role="search">
+              <label class="form-label text-xs" for="search-input">Search Report</label>
+              <input id="search-input" type="search" class="form-item form-item-sm" placeholder="nvme, key:driver, section:Graphics" />
+            </form>
+
+            <p class="text-xs text-muted leading-relaxed m-0">Offline snapshot: filters, search and copy buttons run on the data embedded in this file.</p>
+          </div>
+        </aside>
+
+        <div>
+          <section id="search-results" class="card p-3 mb-3" aria-live="polite" hidden></section>
+          <section class="component-grid" id="component-cards" aria-label="System components"></section>
+        </div>
+      </div>
+
+      <h3 class="text-sm font-bold uppercase tracking-wider text-muted mb-3">All sections</h3>
       <section id="report-cards" class="grid gap-4" style="grid-template-columns: repeat(auto-fit, minmax(400px, 1fr));">{sections}</section>
+      {text_appendix}
     </main>
     <footer class="container text-sm text-muted py-8 text-center border-t">
-      <p class="mb-2">Standalone export generated by inxi-dash.</p>
+      <p class="mb-2">Standalone export generated by inxi-dash · report schema v{schema_version}</p>
       <p class="mb-0 text-xs opacity-75">Powered by <a href="https://github.com/aiedrow/Melt" class="text-muted" target="_blank">Melt UI</a> · Icons from <a href="https://www.flaticon.com/" class="text-muted" target="_blank">Flaticon</a></p>
     </footer>
   </app>
+  <script type="application/schema+json" id="inxi-report-schema">{schema}</script>
+  <script type="application/json" id="inxi-report-data">{data}</script>
+  <script type="application/json" id="inxi-offline-icons">{icons}</script>
   <script>{js}</script>
 </body>
 </html>"##,
@@ -318,28 +549,59 @@ pub fn download_page(report: &SystemReport) -> Result<String, AppError> {
         app_css = app_css,
         sections = sections_html,
         when = when,
-        mode = escape_html(&report.mode),
+        mode = mode,
+        theme_options = theme_options(),
+        report_rows = report_rows,
+        metadata_rows = metadata_rows,
+        text_appendix = text_appendix,
+        schema_version = escape_html(&report.schema_version),
+        schema = schema,
+        data = data,
+        icons = icons,
         js = js
     ))
 }
 
+/// Inline `data:` URL for a binary asset, for documents that must not reference the server.
+pub(crate) fn asset_data_url(path: &str) -> Option<String> {
+    let asset = generated_assets::get_asset(path)?;
+    match &asset.content {
+        generated_assets::AssetContent::Binary(bytes) => Some(format!(
+            "data:{};base64,{}",
+            asset.content_type,
+            STANDARD.encode(bytes)
+        )),
+        generated_assets::AssetContent::Text(_) => None,
+    }
+}
+
+fn export_error(err: serde_json::Error) -> AppError {
+    AppError::Io(std::io::Error::other(err))
+}
+
 fn asset_content(path: &str) -> Result<&'static str, AppError> {
-    match generated_assets::get_asset(path) {
-        Some((_, generated_assets::AssetContent::Text(text))) => Ok(text),
-        Some((_, generated_assets::AssetContent::Binary(_))) => {
+    match generated_assets::get_asset(path).map(|asset| &asset.content) {
+        Some(generated_assets::AssetContent::Text(text)) => Ok(text),
+        Some(generated_assets::AssetContent::Binary(_)) => {
             Err(AppError::AssetNotFound(path.to_string()))
         }
         None => Err(AppError::AssetNotFound(path.to_string())),
     }
 }
 
-struct CategorySections<'a> {
-    label: &'static str,
+/// Fingerprinted URL for an embedded asset, so pages can opt into immutable caching.
+fn asset_url(path: &str) -> &str {
+    generated_assets::get_asset(path).map_or(path, |asset| asset.fingerprinted_path)
+}
+
+pub(crate) struct CategorySections<'a> {
+    pub(crate) label: &'static str,
     keywords: &'static [&'static str],
-    sections: Vec<&'a SystemSection>,
+    pub(crate) sections: Vec<&'a SystemSection>,
 }
 
-fn categorize_sections<'a>(sections: &'a [SystemSection]) -> Vec<CategorySections<'a>> {
+/// Groups sections into the dashboard's hardware categories; shared by the HTML and PDF exports.
+pub(crate) fn categorize_sections<'a>(sections: &'a [SystemSection]) -> Vec<CategorySections<'a>> {
     let mut buckets = CATEGORY_CONFIG
         .iter()
         .map(|config| CategorySections {
@@ -387,11 +649,11 @@ fn categorize_sections<'a>(sections: &'a [SystemSection]) -> Vec<CategorySection
             }
         }
 
-        if let Some(idx) = best_idx {
-            if best_score >= 2 {
-                buckets[idx].sections.push(section);
-                continue;
-            }
+        if let Some(idx) = best_idx
+            && best_score >= 2
+        {
+            buckets[idx].sections.push(section);
+            continue;
         }
 
         if let Some(general) = buckets.iter_mut().find(|bucket| bucket.label == "General") {
@@ -404,3 +666,29 @@ fn categorize_sections<'a>(sections: &'a [SystemSection]) -> Vec<CategorySection
         .filter(|bucket| !bucket.sections.is_empty())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::download_page;
+    use crate::rendering::ExportMetadata;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn embeds_report_data_that_cannot_close_its_script() {
+        let raw = "System:\n  Host: </script><script>alert(1)</script> Kernel: 6.12.68\n";
+        let report = parse_system_report(raw, InxiMode::Basic).expect("report");
+        let page = download_page(&report, &ExportMetadata::default()).expect("page");
+
+        let marker = r#"<script type="application/json" id="inxi-report-data">"#;
+        let start = page.find(marker).expect("embedded report") + marker.len();
+        let data = &page[start..start + page[start..].find("</script>").expect("closed")];
+        assert!(!data.contains('<'));
+
+        let embedded: serde_json::Value = serde_json::from_str(data).expect("valid JSON");
+        assert_eq!(
+            embedded["sections"][0]["entries"][0]["value"],
+            report.sections[0].entries[0].value.as_str()
+        );
+        assert!(page.contains("\"/static/icons/chip.png\":\"data:image/png;base64,"));
+    }
+}
@@ -0,0 +1,222 @@
+// Responsibility: Render a SystemReport, or a diff of two, as GitHub/GitLab-flavoured Markdown for pasting into issues.
+// Design reasoning: A collapsed `<details>` block keeps long reports out of the way while the summary line shows the key facts.
+// Extension guidance: Reuse `lookup::key_facts` for the summary so Markdown, PDF and card exports agree on headline values.
+// Security considerations: Cell text is escaped so report values cannot inject HTML or break the table layout.
+
+use crate::models::{ChangeKind, DiffSide, EntryChange, FieldChange, ReportDiff, SystemReport};
+use crate::rendering::ExportMetadata;
+use crate::services::lookup;
+
+pub fn markdown_report(report: &SystemReport, metadata: &ExportMetadata) -> String {
+    let facts = lookup::key_facts(report);
+    let summary = if facts.is_empty() {
+        "System information".to_string()
+    } else {
+        facts
+            .iter()
+            .map(|(label, value)| format!("{label}: {}", escape_markdown(value)))
+            .collect::<Vec<_>>()
+            .join(" · ")
+    };
+
+    let mut output = String::new();
+    output.push_str("<details>\n");
+    output.push_str(&format!(
+        "<summary><b>System information</b> ({mode}): {summary}</summary>\n\n",
+        mode = escape_markdown(&report.mode)
+    ));
+    let mut provenance = lookup::provenance(report);
+    provenance.push(("Mode", report.mode.clone()));
+    provenance.push(("Schema", report.schema_version.clone()));
+    output.push_str("| Report | |\n| --- | --- |\n");
+    for (label, value) in &provenance {
+        output.push_str(&format!("| {label} | {} |\n", escape_markdown(value)));
+    }
+    if !metadata.rows.is_empty() {
+        output.push_str("\n| Export | |\n| --- | --- |\n");
+        for (label, value) in &metadata.rows {
+            output.push_str(&format!("| {label} | {} |\n", escape_markdown(value)));
+        }
+    }
+
+    for section in &report.sections {
+        output.push_str(&format!("\n#### {}\n\n", escape_markdown(&section.title)));
+        output.push_str("| Key | Value |\n| --- | --- |\n");
+        for entry in &section.entries {
+            output.push_str(&format!(
+                "| {} | {} |\n",
+                escape_markdown(&entry.key),
+                escape_markdown(&entry.value)
+            ));
+        }
+    }
+
+    if let Some(raw) = &metadata.appendix_text {
+        // A fence longer than any backtick run in the text cannot be closed early.
+        let fence = "`".repeat(longest_backtick_run(raw).max(2) + 1);
+        output.push_str(&format!(
+            "\n#### inxi-style text (rebuilt from the exported sections)\n\n{fence}text\n{raw}{fence}\n"
+        ));
+    }
+
+    output.push_str("\n</details>\n");
+    output
+}
+
+/// The compared reports, change counts, then one `Change | Key | Old | New` table per changed section.
+pub fn markdown_diff(diff: &ReportDiff) -> String {
+    let mut output =
+        String::from("## System report diff\n\n| | Old | New |\n| --- | --- | --- |\n");
+    let fields = |side: &DiffSide| {
+        [
+            side.hostname.clone().unwrap_or_else(|| "—".to_string()),
+            side.id.clone(),
+            side.mode.clone(),
+            side.collected_utc.clone(),
+            side.schema_version.clone(),
+        ]
+    };
+    let labels = ["Host", "Report", "Mode", "Collected (UTC)", "Schema"];
+    for (label, (old, new)) in labels
+        .iter()
+        .zip(fields(&diff.old).iter().zip(fields(&diff.new).iter()))
+    {
+        output.push_str(&format!(
+            "| {label} | {} | {} |\n",
+            escape_markdown(old),
+            escape_markdown(new)
+        ));
+    }
+
+    let summary = &diff.summary;
+    output.push_str(&format!(
+        "\n**{} added · {} removed · {} modified**",
+        summary.added, summary.removed, summary.modified
+    ));
+    if summary.ignored > 0 {
+        output.push_str(&format!(
+            " ({} more differed only in ignored fields)",
+            summary.ignored
+        ));
+    }
+    output.push('\n');
+    if !diff.rules.is_empty() {
+        output.push_str(&format!(
+            "\nIgnored: {}\n",
+            escape_markdown(&diff.rules.join(", "))
+        ));
+    }
+    if diff.sections.is_empty() {
+        output.push_str("\nNo differences.\n");
+    }
+
+    for section in &diff.sections {
+        output.push_str(&format!(
+            "\n#### {} ({})\n\n| Change | Key | Old | New |\n| --- | --- | --- | --- |\n",
+            escape_markdown(&section.title),
+            section.kind.as_str()
+        ));
+        for change in &section.changes {
+            let (old, new) = diff_cells(change);
+            output.push_str(&format!(
+                "| {} | {} | {old} | {new} |\n",
+                change.kind.as_str(),
+                escape_markdown(&change.key)
+            ));
+        }
+    }
+    output
+}
+
+/// Modified entries show only the fields that differ, one per line; added and removed entries show the whole value.
+fn diff_cells(change: &EntryChange) -> (String, String) {
+    let cell = |value: Option<&String>| {
+        value.map_or_else(|| "—".to_string(), |value| escape_markdown(value))
+    };
+    if change.kind != ChangeKind::Modified || change.fields.is_empty() {
+        return (cell(change.old.as_ref()), cell(change.new.as_ref()));
+    }
+    let side = |value: fn(&FieldChange) -> Option<&String>| {
+        change
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", escape_markdown(&field.name), cell(value(field))))
+            .collect::<Vec<_>>()
+            .join("<br>")
+    };
+    (
+        side(|field| field.old.as_ref()),
+        side(|field| field.new.as_ref()),
+    )
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|ch| ch != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// Escapes characters that Markdown tables or inline HTML would otherwise interpret (e.g. the `<filter>` redaction marker).
+fn escape_markdown(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '|' | '\\' | '*' | '_' | '`' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '\n' | '\r' => escaped.push(' '),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{markdown_diff, markdown_report};
+    use crate::rendering::ExportMetadata;
+    use crate::services::diff::{DiffRules, diff_reports};
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn renders_collapsible_summary_and_escaped_tables() {
+        let raw = "System:\n  Host: <filter> Kernel: 6.12.68 arch: x86_64\n  Desktop: KDE Distro: Arch | Linux\n";
+        let report = parse_system_report(raw, InxiMode::Basic).expect("report");
+        let metadata = ExportMetadata {
+            rows: vec![("Omitted", "Network".to_string())],
+            appendix_text: Some("System:\n  Kernel: ```\n".to_string()),
+        };
+        let markdown = markdown_report(&report, &metadata);
+
+        assert!(markdown.starts_with("<details>\n<summary><b>System information</b> (basic): Distro: Arch \\| Linux · Kernel: 6.12.68</summary>"));
+        assert!(markdown.contains("| Host | &lt;filter&gt; Kernel: 6.12.68 arch: x86\\_64 |"));
+        assert!(markdown.contains("| Omitted | Network |"));
+        assert!(markdown.contains("````text\nSystem:\n  Kernel: ```\n````\n"));
+        assert!(markdown.trim_end().ends_with("</details>"));
+    }
+
+    #[test]
+    fn renders_diff_with_changed_fields_only() {
+        let old = parse_system_report(
+            "System:\n  Host: box Kernel: 6.12.68 arch: x86_64\n",
+            InxiMode::Basic,
+        )
+        .expect("old");
+        let new = parse_system_report(
+            "System:\n  Host: box Kernel: 6.13.1 arch: x86_64\n",
+            InxiMode::Basic,
+        )
+        .expect("new");
+        let markdown = markdown_diff(&diff_reports(
+            &old,
+            &new,
+            &DiffRules::new(false, &[]).expect("rules"),
+        ));
+
+        assert!(markdown.starts_with("## System report diff\n"));
+        assert!(markdown.contains("**0 added · 0 removed · 1 modified**"));
+        assert!(markdown.contains("| modified | Host | Kernel: 6.12.68 | Kernel: 6.13.1 |"));
+    }
+}
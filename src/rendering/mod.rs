@@ -3,7 +3,23 @@
 // Extension guidance: Add new renderers or helpers here as components and plug them into the exposed API.
 // Security considerations: Rendering code must escape any dynamic text before inclusion to avoid injection.
 
+pub mod bundle;
+pub mod card;
+pub mod export;
+pub mod formats;
+pub mod forum;
 pub mod html_renderer;
+pub mod markdown;
+pub mod pdf;
+pub mod spreadsheet;
 pub mod theme;
 
-pub use html_renderer::{dashboard_page, download_page};
+pub use bundle::bundle_zip;
+pub use card::{card_png, card_svg};
+pub use export::{ExportFormat, ExportLayout, ExportMetadata, ExportOptions, parse_section_names};
+pub use formats::ReportFormat;
+pub use forum::{forum_bbcode, forum_text};
+pub use html_renderer::{dashboard_page, download_page, error_page};
+pub use markdown::{markdown_diff, markdown_report};
+pub use pdf::pdf_report;
+pub use spreadsheet::{inventory_csv, inventory_xlsx};
@@ -0,0 +1,457 @@
+// Responsibility: Render a SystemReport as a paginated A4 PDF (cover summary plus categorized section tables).
+// Design reasoning: printpdf with the PDF base-14 fonts keeps the export pure Rust, offline and free of embedded font files.
+// Extension guidance: Draw through `PdfLayout` so page breaks, running headers and repeated table headers stay consistent.
+// Security considerations: Report text is only ever drawn as string operands; characters outside WinAnsi are replaced, never interpreted.
+
+use std::io;
+
+use printpdf::{
+    BuiltinFont, Color, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Point, Rect, Rgb,
+};
+
+use crate::error::AppError;
+use crate::models::{SystemReport, SystemSection};
+use crate::rendering::ExportMetadata;
+use crate::rendering::html_renderer::{CategorySections, categorize_sections};
+use crate::services::lookup;
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+/// Baseline of the running header; body content starts below `BODY_TOP`.
+const HEADER_BASELINE: f32 = PAGE_HEIGHT - 10.0;
+const BODY_TOP: f32 = PAGE_HEIGHT - 20.0;
+const BODY_BOTTOM: f32 = 15.0;
+const KEY_COLUMN_WIDTH: f32 = 48.0;
+const CELL_PADDING: f32 = 1.5;
+const CELL_FONT_SIZE: f32 = 8.0;
+const CELL_LINE_HEIGHT: f32 = 3.6;
+/// Courier advances every glyph by 0.6 em, which makes wrapping exact without font metrics.
+const COURIER_ADVANCE_EM: f32 = 0.6;
+const PT_TO_MM: f32 = 25.4 / 72.0;
+
+pub fn pdf_report(report: &SystemReport, metadata: &ExportMetadata) -> Result<Vec<u8>, AppError> {
+    let title = format!("Inxi System Report ({})", report.mode);
+    let (document, page, layer) =
+        PdfDocument::new(pdf_text(&title), Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+    let fonts = Fonts {
+        heading: document
+            .add_builtin_font(BuiltinFont::HelveticaBold)
+            .map_err(pdf_error)?,
+        body: document
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(pdf_error)?,
+        cell: document
+            .add_builtin_font(BuiltinFont::Courier)
+            .map_err(pdf_error)?,
+    };
+    let hostname = lookup::hostname(report).unwrap_or_else(|| "unknown host".to_string());
+
+    let mut layout = PdfLayout {
+        layer: document.get_page(page).get_layer(layer),
+        document,
+        fonts,
+        header: format!("{hostname} · {}", report.metadata.collected_utc),
+        page_number: 1,
+        cursor: BODY_TOP,
+    };
+    layout.draw_running_header();
+
+    let categories = categorize_sections(&report.sections);
+    let export = metadata
+        .rows
+        .iter()
+        .map(|(label, value)| (label.to_string(), value.clone()))
+        .collect::<Vec<_>>();
+    layout.cover(
+        report,
+        &hostname,
+        &categories_overview(&categories),
+        &export,
+    );
+    for category in &categories {
+        layout.new_page();
+        layout.heading(category.label, 16.0, 9.0);
+        for section in &category.sections {
+            layout.section_table(section);
+        }
+    }
+    if let Some(raw) = &metadata.appendix_text {
+        layout.new_page();
+        layout.heading(
+            "inxi-style text (rebuilt from the exported sections)",
+            16.0,
+            9.0,
+        );
+        layout.preformatted(raw);
+    }
+
+    layout.document.save_to_bytes().map_err(pdf_error)
+}
+
+struct Fonts {
+    heading: IndirectFontRef,
+    body: IndirectFontRef,
+    cell: IndirectFontRef,
+}
+
+/// Top-down cursor over the current page; `cursor` is the next free baseline in millimetres from the bottom edge.
+struct PdfLayout {
+    document: PdfDocumentReference,
+    layer: PdfLayerReference,
+    fonts: Fonts,
+    header: String,
+    page_number: usize,
+    cursor: f32,
+}
+
+impl PdfLayout {
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .document
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+        self.layer = self.document.get_page(page).get_layer(layer);
+        self.page_number += 1;
+        self.cursor = BODY_TOP;
+        self.draw_running_header();
+    }
+
+    /// Starts a new page unless `height` millimetres still fit above the bottom margin.
+    fn ensure_space(&mut self, height: f32) {
+        if self.cursor - height < BODY_BOTTOM {
+            self.new_page();
+        }
+    }
+
+    fn draw_running_header(&self) {
+        let page = format!("Page {}", self.page_number);
+        self.layer.use_text(
+            pdf_text(&self.header),
+            8.0,
+            Mm(MARGIN),
+            Mm(HEADER_BASELINE),
+            &self.fonts.body,
+        );
+        self.layer.use_text(
+            page.as_str(),
+            8.0,
+            Mm(PAGE_WIDTH - MARGIN - text_width(&page, 8.0, 0.5)),
+            Mm(HEADER_BASELINE),
+            &self.fonts.body,
+        );
+        self.rule(HEADER_BASELINE - 2.0, 0.75);
+    }
+
+    fn heading(&mut self, text: &str, size: f32, advance: f32) {
+        self.ensure_space(advance);
+        self.layer.use_text(
+            pdf_text(text),
+            size,
+            Mm(MARGIN),
+            Mm(self.cursor - size * PT_TO_MM),
+            &self.fonts.heading,
+        );
+        self.cursor -= advance;
+    }
+
+    fn cover(
+        &mut self,
+        report: &SystemReport,
+        hostname: &str,
+        overview: &[(String, String)],
+        export: &[(String, String)],
+    ) {
+        self.cursor -= 20.0;
+        self.heading("Inxi System Report", 24.0, 14.0);
+        self.layer.use_text(
+            pdf_text(hostname),
+            14.0,
+            Mm(MARGIN),
+            Mm(self.cursor),
+            &self.fonts.body,
+        );
+        self.cursor -= 16.0;
+
+        let mut summary = lookup::key_facts(report)
+            .into_iter()
+            .chain(lookup::provenance(report))
+            .map(|(label, value)| (label.to_string(), value))
+            .collect::<Vec<_>>();
+        summary.extend([
+            ("Mode".to_string(), report.mode.clone()),
+            ("Schema".to_string(), report.schema_version.clone()),
+        ]);
+        self.heading("Summary", 12.0, 7.0);
+        self.table(None, &summary);
+
+        self.cursor -= 4.0;
+        self.heading("Contents", 12.0, 7.0);
+        self.table(None, overview);
+
+        if !export.is_empty() {
+            self.cursor -= 4.0;
+            self.heading("Export", 12.0, 7.0);
+            self.table(None, export);
+        }
+    }
+
+    fn section_table(&mut self, section: &SystemSection) {
+        let rows = section
+            .entries
+            .iter()
+            .map(|entry| (entry.key.clone(), entry.value.clone()))
+            .collect::<Vec<_>>();
+        self.cursor -= 2.0;
+        self.table(Some(&section.title), &rows);
+    }
+
+    /// Draws a two-column table, wrapping cells and repeating the title and column header after a page break.
+    fn table(&mut self, title: Option<&str>, rows: &[(String, String)]) {
+        let key_chars = column_chars(KEY_COLUMN_WIDTH);
+        let value_chars = column_chars(PAGE_WIDTH - 2.0 * MARGIN - KEY_COLUMN_WIDTH);
+        let header_height = CELL_LINE_HEIGHT + 2.0 * CELL_PADDING;
+        let title_height = if title.is_some() { 6.0 } else { 0.0 };
+
+        // Keep the title, column header and first row together.
+        let first_row = rows.first().map_or(0, |(key, value)| {
+            wrap(key, key_chars)
+                .len()
+                .max(wrap(value, value_chars).len())
+        });
+        self.ensure_space(title_height + header_height + row_height(first_row));
+        self.table_header(title, false);
+
+        for (key, value) in rows {
+            let key_lines = wrap(key, key_chars);
+            let value_lines = wrap(value, value_chars);
+            let lines = key_lines.len().max(value_lines.len());
+            if self.cursor - row_height(lines) < BODY_BOTTOM {
+                self.new_page();
+                self.table_header(title, true);
+            }
+
+            // Only rows taller than a whole page are split, line by line.
+            let mut start = 0;
+            while start < lines {
+                let available = ((self.cursor - BODY_BOTTOM - 2.0 * CELL_PADDING)
+                    / CELL_LINE_HEIGHT)
+                    .floor()
+                    .max(1.0) as usize;
+                let end = lines.min(start + available);
+                self.row(&key_lines, &value_lines, start..end);
+                start = end;
+                if start < lines {
+                    self.new_page();
+                    self.table_header(title, true);
+                }
+            }
+        }
+    }
+
+    /// Draws monospaced lines, keeping their indentation and wrapping long ones under it.
+    fn preformatted(&mut self, text: &str) {
+        let width = column_chars(PAGE_WIDTH - 2.0 * MARGIN);
+        for line in text.lines() {
+            let indent = line.len() - line.trim_start().len();
+            let pad = " ".repeat(indent.min(width / 2));
+            for wrapped in wrap(line, width - pad.len()) {
+                self.ensure_space(CELL_LINE_HEIGHT);
+                self.layer.use_text(
+                    format!("{pad}{wrapped}"),
+                    CELL_FONT_SIZE,
+                    Mm(MARGIN + CELL_PADDING),
+                    Mm(self.cursor - CELL_LINE_HEIGHT),
+                    &self.fonts.cell,
+                );
+                self.cursor -= CELL_LINE_HEIGHT;
+            }
+        }
+    }
+
+    fn table_header(&mut self, title: Option<&str>, continued: bool) {
+        if let Some(title) = title {
+            let label = if continued {
+                format!("{title} (continued)")
+            } else {
+                title.to_string()
+            };
+            self.heading(&label, 10.0, 6.0);
+        }
+
+        let height = CELL_LINE_HEIGHT + 2.0 * CELL_PADDING;
+        self.fill(self.cursor - height, height, 0.88);
+        let baseline = self.cursor - CELL_PADDING - CELL_LINE_HEIGHT + 0.9;
+        self.layer.use_text(
+            "Key",
+            CELL_FONT_SIZE,
+            Mm(MARGIN + CELL_PADDING),
+            Mm(baseline),
+            &self.fonts.heading,
+        );
+        self.layer.use_text(
+            "Value",
+            CELL_FONT_SIZE,
+            Mm(MARGIN + KEY_COLUMN_WIDTH + CELL_PADDING),
+            Mm(baseline),
+            &self.fonts.heading,
+        );
+        self.cursor -= height;
+    }
+
+    fn row(&mut self, key_lines: &[String], value_lines: &[String], lines: std::ops::Range<usize>) {
+        let mut baseline = self.cursor - CELL_PADDING - CELL_LINE_HEIGHT + 0.9;
+        for index in lines.clone() {
+            for (column_x, cell) in [
+                (MARGIN, key_lines.get(index)),
+                (MARGIN + KEY_COLUMN_WIDTH, value_lines.get(index)),
+            ] {
+                if let Some(text) = cell {
+                    self.layer.use_text(
+                        text.as_str(),
+                        CELL_FONT_SIZE,
+                        Mm(column_x + CELL_PADDING),
+                        Mm(baseline),
+                        &self.fonts.cell,
+                    );
+                }
+            }
+            baseline -= CELL_LINE_HEIGHT;
+        }
+        self.cursor -= row_height(lines.len());
+        self.rule(self.cursor, 0.25);
+    }
+
+    fn fill(&self, bottom: f32, height: f32, grey: f32) {
+        self.layer
+            .set_fill_color(Color::Rgb(Rgb::new(grey, grey, grey, None)));
+        self.layer.add_rect(Rect::new(
+            Mm(MARGIN),
+            Mm(bottom),
+            Mm(PAGE_WIDTH - MARGIN),
+            Mm(bottom + height),
+        ));
+        self.layer
+            .set_fill_color(Color::Rgb(Rgb::new(0.0, 0.0, 0.0, None)));
+    }
+
+    fn rule(&self, y: f32, thickness: f32) {
+        self.layer.set_outline_thickness(thickness);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN), Mm(y)), false),
+                (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+}
+
+fn categories_overview(categories: &[CategorySections<'_>]) -> Vec<(String, String)> {
+    categories
+        .iter()
+        .map(|category| {
+            let titles = category
+                .sections
+                .iter()
+                .map(|section| section.title.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            (category.label.to_string(), titles)
+        })
+        .collect()
+}
+
+fn row_height(lines: usize) -> f32 {
+    lines.max(1) as f32 * CELL_LINE_HEIGHT + 2.0 * CELL_PADDING
+}
+
+fn column_chars(width: f32) -> usize {
+    ((width - 2.0 * CELL_PADDING) / text_width("M", CELL_FONT_SIZE, COURIER_ADVANCE_EM)) as usize
+}
+
+fn text_width(text: &str, size: f32, advance_em: f32) -> f32 {
+    text.chars().count() as f32 * size * advance_em * PT_TO_MM
+}
+
+/// Word-wraps `text` to `width` characters, hard-breaking words longer than a line.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in pdf_text(text).split_whitespace() {
+        let mut word = word.to_string();
+        loop {
+            let used = current.chars().count();
+            let needed = word.chars().count() + usize::from(used > 0);
+            if used + needed <= width {
+                if used > 0 {
+                    current.push(' ');
+                }
+                current.push_str(&word);
+                break;
+            }
+            if used > 0 {
+                lines.push(std::mem::take(&mut current));
+                continue;
+            }
+            let split = word
+                .char_indices()
+                .nth(width)
+                .map_or(word.len(), |(index, _)| index);
+            let rest = word.split_off(split);
+            lines.push(word);
+            word = rest;
+            if word.is_empty() {
+                break;
+            }
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Restricts text to what the base-14 fonts can draw (WinAnsi, approximated by Latin-1) and strips control characters.
+fn pdf_text(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            '\t' | '\n' | '\r' => ' ',
+            ch if ch.is_control() => '?',
+            ch if (ch as u32) < 0x100 => ch,
+            _ => '?',
+        })
+        .collect()
+}
+
+fn pdf_error(error: printpdf::Error) -> AppError {
+    AppError::Io(io::Error::other(format!("PDF rendering failed: {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pdf_report, wrap};
+    use crate::rendering::ExportMetadata;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn paginates_long_reports_and_wraps_cells() {
+        let mut raw = String::from("System:\n  Host: box Kernel: 6.12.68 arch: x86_64\nDrives:\n");
+        for index in 0..120 {
+            raw.push_str(&format!("  ID-{index}: /dev/sd{index} size: 1 TiB\n"));
+        }
+        let report = parse_system_report(&raw, InxiMode::Basic).expect("report");
+        let metadata = ExportMetadata {
+            rows: vec![("Omitted", "none".to_string())],
+            appendix_text: Some(raw.clone()),
+        };
+        let pdf = pdf_report(&report, &metadata).expect("pdf");
+        let pages = String::from_utf8_lossy(&pdf).matches("/Type/Page/").count();
+
+        assert!(pdf.starts_with(b"%PDF-"));
+        // Cover, one page per category, a continuation of the long Drives table, and the text appendix.
+        assert!(pages >= 5, "expected a paginated table, got {pages} pages");
+        assert_eq!(wrap("abcdefgh ij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("", 4), vec![String::new()]);
+    }
+}
@@ -0,0 +1,262 @@
+// Responsibility: Render a SystemReport as inventory spreadsheets: CSV entry rows and an XLSX workbook with Summary, Entries and Export sheets.
+// Design reasoning: Every row leads with the host name so files from many machines can be concatenated or merged without losing provenance.
+// Extension guidance: Add typed Summary columns through `lookup::Inventory`, keeping units in the header (GiB) and values numeric.
+// Security considerations: XLSX cells are written as strings or numbers, never formulas; CSV reuses the formula-safe quoting of `formats`.
+
+use std::io;
+
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, Worksheet, XlsxError};
+
+use crate::error::AppError;
+use crate::models::SystemReport;
+use crate::rendering::ExportMetadata;
+use crate::rendering::formats::csv_field;
+use crate::services::lookup::{self, Inventory};
+
+const ENTRY_HEADERS: [&str; 4] = ["Host", "Section", "Key", "Value"];
+const SUMMARY_HEADERS: [&str; 17] = [
+    "Host",
+    "Mode",
+    "Collected (UTC)",
+    "Distro",
+    "Kernel",
+    "CPU model",
+    "CPU cores",
+    "GPU",
+    "RAM total (GiB)",
+    "Disks",
+    "Disk total (GiB)",
+    "Disk sizes (GiB)",
+    "Disk models",
+    "Machine ID",
+    "inxi version",
+    "inxi-dash version",
+    "Collection time (s)",
+];
+/// Section name of the CSV rows carrying the export metadata block; inxi has no section by this name.
+const EXPORT_SECTION: &str = "Export";
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// One `host,section,key,value` row per entry, RFC 4180 quoted, after leading `Export` rows that
+/// record the metadata block in the same columns, so filtered or masked files say so and still concatenate.
+pub fn inventory_csv(report: &SystemReport, metadata: &ExportMetadata) -> String {
+    let host = lookup::hostname(report).unwrap_or_default();
+    let mut output = String::from("host,section,key,value\r\n");
+    let export_rows = metadata
+        .rows
+        .iter()
+        .map(|(label, value)| (EXPORT_SECTION, *label, value.as_str()));
+    let entry_rows = report.sections.iter().flat_map(|section| {
+        section.entries.iter().map(|entry| {
+            (
+                section.title.as_str(),
+                entry.key.as_str(),
+                entry.value.as_str(),
+            )
+        })
+    });
+    for (section, key, value) in export_rows.chain(entry_rows) {
+        let row = [host.as_str(), section, key, value]
+            .map(csv_field)
+            .join(",");
+        output.push_str(&row);
+        output.push_str("\r\n");
+    }
+    output
+}
+
+/// Workbook with a one-row `Summary` sheet of typed fields, an `Entries` sheet (one row per entry),
+/// an `Export` sheet with the metadata block and, when requested, a `Text` sheet with one inxi-style line per row.
+pub fn inventory_xlsx(
+    report: &SystemReport,
+    metadata: &ExportMetadata,
+) -> Result<Vec<u8>, AppError> {
+    let inventory = lookup::inventory(report);
+    let host = inventory.hostname.clone().unwrap_or_default();
+    let header = Format::new().set_bold();
+
+    let mut workbook = Workbook::new();
+    summary_sheet(workbook.add_worksheet(), report, &inventory, &header).map_err(xlsx_error)?;
+
+    let entries = workbook.add_worksheet();
+    entries.set_name("Entries").map_err(xlsx_error)?;
+    write_header(entries, &ENTRY_HEADERS, &header).map_err(xlsx_error)?;
+    let mut row = 0;
+    for section in &report.sections {
+        for entry in &section.entries {
+            row += 1;
+            for (col, value) in [&host, &section.title, &entry.key, &entry.value]
+                .into_iter()
+                .enumerate()
+            {
+                entries
+                    .write_string(row, col as u16, value)
+                    .map_err(xlsx_error)?;
+            }
+        }
+    }
+    entries
+        .autofilter(0, 0, row, ENTRY_HEADERS.len() as u16 - 1)
+        .and_then(|sheet| sheet.set_column_width(1, 16))
+        .and_then(|sheet| sheet.set_column_width(2, 22))
+        .and_then(|sheet| sheet.set_column_width(3, 100))
+        .map_err(xlsx_error)?;
+
+    export_sheet(workbook.add_worksheet(), metadata, &header).map_err(xlsx_error)?;
+    if let Some(raw) = &metadata.appendix_text {
+        let sheet = workbook.add_worksheet();
+        sheet.set_name("Text").map_err(xlsx_error)?;
+        for (row, line) in raw.lines().enumerate() {
+            sheet
+                .write_string(row as u32, 0, line)
+                .map_err(xlsx_error)?;
+        }
+        sheet.set_column_width(0, 120).map_err(xlsx_error)?;
+    }
+
+    workbook.save_to_buffer().map_err(xlsx_error)
+}
+
+fn summary_sheet(
+    sheet: &mut Worksheet,
+    report: &SystemReport,
+    inventory: &Inventory,
+    header: &Format,
+) -> Result<(), XlsxError> {
+    sheet.set_name("Summary")?;
+    write_header(sheet, &SUMMARY_HEADERS, header)?;
+
+    let text = |value: &Option<String>| value.clone().unwrap_or_default();
+    let sizes = inventory
+        .disks
+        .iter()
+        .filter_map(|disk| disk.size)
+        .collect::<Vec<_>>();
+    let models = inventory
+        .disks
+        .iter()
+        .map(|disk| disk.model.clone().unwrap_or_else(|| disk.device.clone()))
+        .collect::<Vec<_>>()
+        .join("; ");
+    let size_list = sizes
+        .iter()
+        .map(|bytes| format!("{:.2}", bytes / GIB))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    sheet.write_string(1, 0, text(&inventory.hostname))?;
+    sheet.write_string(1, 1, &report.mode)?;
+    let collected = ExcelDateTime::from_timestamp(report.timestamp as i64)?;
+    sheet.write_datetime_with_format(
+        1,
+        2,
+        &collected,
+        &Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+    )?;
+    sheet.write_string(1, 3, text(&inventory.distro))?;
+    sheet.write_string(1, 4, text(&inventory.kernel))?;
+    sheet.write_string(1, 5, text(&inventory.cpu_model))?;
+    if let Some(cores) = inventory.cpu_cores {
+        sheet.write_number(1, 6, cores)?;
+    }
+    sheet.write_string(1, 7, text(&inventory.gpu))?;
+    let gib = Format::new().set_num_format("0.00");
+    if let Some(total) = inventory.memory_total {
+        sheet.write_number_with_format(1, 8, total / GIB, &gib)?;
+    }
+    sheet.write_number(1, 9, inventory.disks.len() as f64)?;
+    if !sizes.is_empty() {
+        sheet.write_number_with_format(1, 10, sizes.iter().sum::<f64>() / GIB, &gib)?;
+    }
+    sheet.write_string(1, 11, size_list)?;
+    sheet.write_string(1, 12, models)?;
+    let metadata = &report.metadata;
+    sheet.write_string(1, 13, text(&metadata.machine_id_hash))?;
+    sheet.write_string(1, 14, text(&metadata.inxi_version))?;
+    sheet.write_string(1, 15, &metadata.inxi_dash_version)?;
+    if let Some(ms) = metadata.collection_ms {
+        sheet.write_number_with_format(
+            1,
+            16,
+            ms as f64 / 1000.0,
+            &Format::new().set_num_format("0.0"),
+        )?;
+    }
+
+    for (col, width) in [
+        (0, 18),
+        (2, 20),
+        (3, 20),
+        (5, 30),
+        (7, 30),
+        (12, 30),
+        (13, 18),
+    ] {
+        sheet.set_column_width(col, width)?;
+    }
+    Ok(())
+}
+
+fn export_sheet(
+    sheet: &mut Worksheet,
+    metadata: &ExportMetadata,
+    header: &Format,
+) -> Result<(), XlsxError> {
+    sheet.set_name("Export")?;
+    write_header(sheet, &["Setting", "Value"], header)?;
+    for (row, (label, value)) in metadata.rows.iter().enumerate() {
+        sheet.write_string(row as u32 + 1, 0, *label)?;
+        sheet.write_string(row as u32 + 1, 1, value)?;
+    }
+    sheet.set_column_width(0, 16)?;
+    sheet.set_column_width(1, 60)?;
+    Ok(())
+}
+
+fn write_header(sheet: &mut Worksheet, headers: &[&str], format: &Format) -> Result<(), XlsxError> {
+    for (col, title) in headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *title, format)?;
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    Ok(())
+}
+
+fn xlsx_error(error: XlsxError) -> AppError {
+    AppError::Io(io::Error::other(format!("XLSX rendering failed: {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inventory_csv, inventory_xlsx};
+    use crate::rendering::ExportMetadata;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn writes_host_prefixed_rows_and_a_zip_workbook() {
+        let raw = "System:\n  Host: box Kernel: 6.12.68 arch: x86_64\nDrives:\n  ID-1: /dev/sda model: =cmd size: 1 TiB\n";
+        let report = parse_system_report(raw, InxiMode::Basic).expect("report");
+
+        let metadata = ExportMetadata {
+            rows: vec![("Omitted", "Network, Bluetooth".to_string())],
+            ..ExportMetadata::default()
+        };
+        let csv = inventory_csv(&report, &metadata);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("host,section,key,value"));
+        assert_eq!(
+            lines.next(),
+            Some("box,Export,Omitted,\"Network, Bluetooth\"")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("box,System,Host,box Kernel: 6.12.68 arch: x86_64")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("box,Drives,ID-1,/dev/sda model: =cmd size: 1 TiB")
+        );
+
+        let xlsx = inventory_xlsx(&report, &ExportMetadata::default()).expect("xlsx");
+        assert!(xlsx.starts_with(b"PK\x03\x04"));
+    }
+}
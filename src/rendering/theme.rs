@@ -9,3 +9,68 @@ pub const THEME_OPTIONS: &[(&str, &str)] = &[
     ("royal", "Royal"),
     ("glass", "Glass"),
 ];
+
+/// Colours of one dashboard theme for renderers that cannot use the stylesheet (e.g. the spec card).
+/// Values mirror the `[theme="..."]` blocks in `static/css/melt.css`.
+pub struct Palette {
+    pub name: &'static str,
+    /// Background gradient stops; identical stops give a flat fill.
+    pub background: (&'static str, &'static str),
+    pub surface: &'static str,
+    pub border: &'static str,
+    pub text: &'static str,
+    pub text_muted: &'static str,
+    pub primary: &'static str,
+    /// Dark backgrounds need the black line-art icons inverted to stay visible.
+    pub dark: bool,
+}
+
+pub const PALETTES: &[Palette] = &[
+    Palette {
+        name: "default",
+        background: ("#ffffff", "#ffffff"),
+        surface: "#f9fafb",
+        border: "#e5e7eb",
+        text: "#111827",
+        text_muted: "#4b5563",
+        primary: "#3b82f6",
+        dark: false,
+    },
+    Palette {
+        name: "dark",
+        background: ("#111111", "#111111"),
+        surface: "#1a1a1a",
+        border: "#2a2a2a",
+        text: "#f9fafb",
+        text_muted: "#9ca3af",
+        primary: "#60a5fa",
+        dark: true,
+    },
+    Palette {
+        name: "royal",
+        background: ("#120a20", "#120a20"),
+        surface: "#1d1333",
+        border: "#3d2860",
+        text: "#e9d5ff",
+        text_muted: "#c4b5fd",
+        primary: "#a855f7",
+        dark: true,
+    },
+    Palette {
+        name: "glass",
+        background: ("#1e1e2e", "#2d1b4e"),
+        surface: "#2f2f3d",
+        border: "#4a4a58",
+        text: "#ffffff",
+        text_muted: "#b4b4bc",
+        primary: "#3b82f6",
+        dark: true,
+    },
+];
+
+/// Looks up a palette by theme name (case-insensitive).
+pub fn palette(name: &str) -> Option<&'static Palette> {
+    PALETTES
+        .iter()
+        .find(|palette| palette.name.eq_ignore_ascii_case(name.trim()))
+}
@@ -1,11 +1,12 @@
-// Responsibility: Expose the /api/system endpoint that proxies to the inxi service.
-// Design reasoning: Query parsing, mode validation, and JSON serialization stay within a focused handler.
-// Extension guidance: Add pagination or caching headers here when performance enhancements are introduced.
-// Security considerations: All mode inputs are validated against a fixed allowlist and sanitized in the parser.
+// Responsibility: Expose the system report endpoint (/api/v1/system and its legacy /api/system alias).
+// Design reasoning: Query parsing, mode validation, and format negotiation stay within a focused handler.
+// Extension guidance: New output formats belong in rendering::formats; this handler only negotiates and sets headers.
+// Security considerations: All mode inputs are validated against a fixed allowlist, and the caller role gates mode and redaction.
 
 use axum::{
     extract::{Extension, Query},
-    response::Json,
+    http::{HeaderMap, header},
+    response::{IntoResponse, Response},
 };
 use serde::Deserialize;
 use std::sync::Arc;
@@ -13,19 +14,64 @@ use std::sync::Arc;
 use crate::config::DEFAULT_MODE;
 use crate::error::AppError;
 use crate::models::SystemReport;
-use crate::services::{InxiMode, InxiService};
+use crate::rendering::ReportFormat;
+use crate::routes::caller::Caller;
+use crate::services::{InxiMode, InxiService, RateLimiter};
 
 #[derive(Deserialize)]
-pub(crate) struct ModeQuery {
+pub(crate) struct SystemQuery {
     mode: Option<String>,
+    redact: Option<bool>,
+    /// Overrides the Accept header: json, yaml, toml, csv or text.
+    format: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ModeQuery {
+    pub(crate) mode: Option<String>,
+    pub(crate) redact: Option<bool>,
 }
 
 pub async fn api_handler(
     Extension(service): Extension<Arc<InxiService>>,
-    Query(query): Query<ModeQuery>,
-) -> Result<Json<SystemReport>, AppError> {
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    headers: HeaderMap,
+    Query(query): Query<SystemQuery>,
+) -> Result<Response, AppError> {
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok());
+    // Negotiate before collecting so an unsupported format never spawns inxi.
+    let format = ReportFormat::negotiate(query.format.as_deref(), accept)?;
+    let mode_query = ModeQuery {
+        mode: query.mode,
+        redact: query.redact,
+    };
+    let report = collect_report(&service, &limiter, &caller, &mode_query).await?;
+    let body = format.render(&report)?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type()),
+            (header::VARY, "Accept"),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Authorizes, throttles, runs inxi, and applies the caller's section and redaction restrictions.
+pub(crate) async fn collect_report(
+    service: &InxiService,
+    limiter: &RateLimiter,
+    caller: &Caller,
+    query: &ModeQuery,
+) -> Result<SystemReport, AppError> {
     let mode = query.mode.as_deref().unwrap_or(DEFAULT_MODE);
     let final_mode = InxiMode::parse(mode)?;
-    let report = service.run(final_mode).await?;
-    Ok(Json(report))
+    let redact = caller.role.authorize(final_mode, query.redact)?;
+    limiter.check(&caller.client)?;
+    let mut report = service.run(final_mode).await?;
+    caller.role.restrict(&mut report, redact);
+    Ok(report)
 }
@@ -0,0 +1,103 @@
+// Responsibility: Resolve the role and rate-limit key of the caller before report handlers run.
+// Design reasoning: An extractor keeps authorization out of handler bodies and rejects bad tokens uniformly.
+// Extension guidance: Accept additional credential sources (mTLS identities) here without touching handlers.
+// Security considerations: Tokens come only from the `Authorization` header or an HttpOnly session cookie, never query strings that leak into logs.
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::request::Parts,
+    http::{HeaderMap, header},
+};
+use std::{net::SocketAddr, sync::Arc};
+
+use crate::config::SESSION_COOKIE;
+use crate::error::AppError;
+use crate::services::{AccessPolicy, Role};
+
+/// How the caller was identified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Credential {
+    Anonymous,
+    /// `Authorization: Bearer` header.
+    Token,
+    /// Session cookie from `POST /api/v1/session`, used by the dashboard since fetch and EventSource cannot add headers.
+    Session,
+}
+
+impl Credential {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Credential::Anonymous => "anonymous",
+            Credential::Token => "token",
+            Credential::Session => "session",
+        }
+    }
+}
+
+pub struct Caller {
+    pub role: Role,
+    /// Stable key used for per-client rate limiting: the token when present, otherwise the peer IP.
+    pub client: String,
+    pub credential: Credential,
+}
+
+impl<S> FromRequestParts<S> for Caller
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let policy = parts
+            .extensions
+            .get::<Arc<AccessPolicy>>()
+            .cloned()
+            .ok_or_else(|| AppError::Config("access policy not installed".to_string()))?;
+
+        let bearer = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty());
+
+        // An explicit header wins; a stale session cookie falls back to anonymous rather than failing.
+        let (token, credential) = match bearer {
+            Some(token) => (Some(token.to_string()), Credential::Token),
+            None => match session_cookie(&parts.headers).and_then(|id| policy.session_token(id)) {
+                Some(token) => (Some(token), Credential::Session),
+                None => (None, Credential::Anonymous),
+            },
+        };
+
+        let role = policy.resolve(token.as_deref())?;
+        let client = match token {
+            Some(token) => format!("token:{token}"),
+            None => parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| format!("ip:{}", addr.ip()))
+                .unwrap_or_else(|| "ip:unknown".to_string()),
+        };
+
+        Ok(Caller {
+            role,
+            client,
+            credential,
+        })
+    }
+}
+
+/// Value of the session cookie, if the request carries one.
+pub(crate) fn session_cookie(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == SESSION_COOKIE)
+        .map(|(_, value)| value.trim())
+        .filter(|value| !value.is_empty())
+}
@@ -0,0 +1,93 @@
+// Responsibility: Serve POST /api/v1/diff, comparing an uploaded report with a second upload or the live report.
+// Design reasoning: The live side goes through `collect_report`, so it is authorized, throttled and restricted like /api/v1/system.
+// Extension guidance: New output formats are another `format` value rendered from the same `ReportDiff`.
+// Security considerations: Uploaded reports are only parsed and compared; JSON bodies are required so browsers preflight cross-site posts.
+
+use axum::{
+    extract::{Extension, Query, rejection::JsonRejection},
+    http::header,
+    response::{IntoResponse, Json, Response},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::config::DOWNLOAD_FILENAME_PREFIX;
+use crate::error::AppError;
+use crate::models::{DiffRequest, DiffSide};
+use crate::rendering::markdown_diff;
+use crate::routes::api::{ModeQuery, collect_report};
+use crate::routes::caller::Caller;
+use crate::services::diff::{DiffRules, diff_reports};
+use crate::services::{InxiMode, InxiService, RateLimiter};
+
+#[derive(Deserialize)]
+pub(crate) struct DiffQuery {
+    /// `json` (default) or `md`.
+    format: Option<String>,
+}
+
+pub async fn diff_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<DiffQuery>,
+    body: Result<Json<DiffRequest>, JsonRejection>,
+) -> Result<Response, AppError> {
+    let Json(request) = body.map_err(|rejection| AppError::InvalidQuery(rejection.body_text()))?;
+    let markdown = match query.format.as_deref().unwrap_or("json") {
+        "json" => false,
+        "md" => true,
+        other => {
+            return Err(AppError::InvalidQuery(format!(
+                "unknown diff format '{other}'; use json or md"
+            )));
+        }
+    };
+    let rules = DiffRules::new(request.volatile.unwrap_or(true), &request.ignore)?;
+    let new = match request.new {
+        Some(_) if request.mode.is_some() => {
+            return Err(AppError::InvalidQuery(
+                "`mode` selects the live report, so it cannot be combined with `new`".to_string(),
+            ));
+        }
+        Some(report) => report,
+        None => {
+            let live = ModeQuery {
+                mode: Some(request.mode.unwrap_or_else(|| request.old.mode.clone())),
+                redact: None,
+            };
+            collect_report(&service, &limiter, &caller, &live).await?
+        }
+    };
+
+    let diff = diff_reports(&request.old, &new, &rules);
+    if !markdown {
+        return Ok(Json(diff).into_response());
+    }
+    let disposition = format!(
+        "attachment; filename=\"{DOWNLOAD_FILENAME_PREFIX}-diff-{}-{}.md\"",
+        side_date(&diff.old),
+        side_date(&diff.new)
+    );
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "text/markdown; charset=utf-8".to_string(),
+            ),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        markdown_diff(&diff),
+    )
+        .into_response())
+}
+
+/// `YYYY-MM-DD` of the collection, or the mode for documents from before reports carried metadata.
+/// Uploaded text never reaches the header unchecked: dates must be digits and modes a known mode.
+fn side_date(side: &DiffSide) -> &str {
+    side.collected_utc
+        .get(..10)
+        .filter(|date| date.chars().all(|ch| ch.is_ascii_digit() || ch == '-'))
+        .or_else(|| InxiMode::parse(&side.mode).ok().map(|mode| mode.as_str()))
+        .unwrap_or("report")
+}
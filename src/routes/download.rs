@@ -1,7 +1,7 @@
-// Responsibility: Build a standalone HTML snapshot and stream it with a download header.
-// Design reasoning: This handler mirrors the JSON API but enriches the report with inline assets and disposition headers.
-// Extension guidance: Add additional query controls (e.g., include logs) by layering on top of this handler.
-// Security considerations: Download export only reflects sanitized report data and known assets; no template injections are allowed.
+// Responsibility: Build a downloadable export (standalone HTML, Markdown, ...) and stream it with a download header.
+// Design reasoning: This handler mirrors the JSON API; rendering::ExportFormat picks the renderer, extension and media type.
+// Extension guidance: New query controls parse into ExportOptions here and take effect in `ExportOptions::apply`.
+// Security considerations: Download export only reflects sanitized, role-restricted report data and known assets; no template injections are allowed.
 
 use axum::{
     body::Body,
@@ -11,34 +11,221 @@ use axum::{
 };
 use serde::Deserialize;
 use std::{io, sync::Arc};
+use uuid::Uuid;
 
-use crate::config::{DEFAULT_MODE, DOWNLOAD_FILENAME_PREFIX};
+use crate::config::DEFAULT_MODE;
 use crate::error::AppError;
-use crate::rendering::download_page;
-use crate::services::{InxiMode, InxiService};
+use crate::models::SystemReport;
+use crate::rendering::{
+    ExportFormat, ExportLayout, ExportOptions, bundle_zip, parse_section_names,
+};
+use crate::routes::caller::Caller;
+use crate::services::redaction::RedactionProfile;
+use crate::services::{InxiMode, InxiService, RateLimiter, SnapshotStore};
 
 #[derive(Deserialize)]
 pub(crate) struct DownloadQuery {
     mode: Option<String>,
+    /// ID of a recently collected report to export instead of running inxi; excludes `mode`.
+    report: Option<String>,
+    /// ID of a stored snapshot to export; excludes `mode` and `report`.
+    snapshot: Option<String>,
+    redact: Option<bool>,
+    /// `html` (default), `md`, `pdf`, `text`, `bbcode`, `csv` or `xlsx`.
+    format: Option<String>,
+    /// Character budget for the forum formats.
+    limit: Option<usize>,
+    /// Comma-separated section titles or category labels to keep.
+    include: Option<String>,
+    /// Comma-separated section titles or category labels to drop.
+    exclude: Option<String>,
+    /// `none`, `standard` or `strict`; an alternative to `redact`.
+    redaction: Option<String>,
+    /// `detailed` (default) or `compact`.
+    layout: Option<String>,
+    /// Append the exported sections as inxi-style text, rebuilt from the filtered report.
+    text_appendix: Option<bool>,
 }
 
 pub async fn download_handler(
     Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    Extension(store): Extension<Arc<SnapshotStore>>,
+    caller: Caller,
     Query(query): Query<DownloadQuery>,
 ) -> Result<Response, AppError> {
-    let mode = query.mode.as_deref().unwrap_or(DEFAULT_MODE);
+    let held = match query.snapshot.as_deref() {
+        Some(_) if query.report.is_some() || query.mode.is_some() => {
+            return Err(AppError::InvalidQuery(
+                "use `snapshot` on its own, without `report` or `mode`".to_string(),
+            ));
+        }
+        Some(id) => {
+            let id = id.to_string();
+            Some(store.blocking(move |store| store.get(&id)).await?.report)
+        }
+        None => query
+            .report
+            .as_deref()
+            .map(|id| held_report(&service, id, query.mode.is_some()))
+            .transpose()?,
+    };
+    let mode = held
+        .as_ref()
+        .map_or(query.mode.as_deref().unwrap_or(DEFAULT_MODE), |report| {
+            report.mode.as_str()
+        });
     let final_mode = InxiMode::parse(mode)?;
-    let report = service.run(final_mode).await?;
-    let html = download_page(&report)?;
-    let filename = format!("{}-{}.html", DOWNLOAD_FILENAME_PREFIX, report.mode);
+    let format = query
+        .format
+        .as_deref()
+        .map(ExportFormat::parse)
+        .transpose()?
+        .unwrap_or(ExportFormat::Html);
+    let requested = requested_profile(query.redaction.as_deref(), query.redact)?.or(format
+        .redacts_by_default()
+        .then_some(RedactionProfile::Standard));
+    let redact = caller
+        .role
+        .authorize(final_mode, requested.map(RedactionProfile::masks))?;
+    let options = ExportOptions {
+        include: section_names(query.include.as_deref())?,
+        exclude: section_names(query.exclude.as_deref())?,
+        redaction: granted_profile(requested, redact),
+        layout: query
+            .layout
+            .as_deref()
+            .map(ExportLayout::parse)
+            .transpose()?
+            .unwrap_or_default(),
+        text_appendix: query.text_appendix.unwrap_or(false),
+        role: Some(caller.role),
+        ..ExportOptions::new(query.limit)?
+    };
+    options.validate(format)?;
+    // Held reports and snapshots are already collected, so only a fresh run spends rate-limit budget.
+    let report = match held {
+        Some(report) => report,
+        None => {
+            limiter.check(&caller.client)?;
+            service.run(final_mode).await?
+        }
+    };
+    let body = format.render(&report, &options)?;
+    let filename = options.filename(&report, format);
     let disposition = format!("attachment; filename=\"{filename}\"");
 
     let response = Response::builder()
         .status(StatusCode::OK)
-        .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .header(header::CONTENT_TYPE, format.content_type())
         .header(header::CONTENT_DISPOSITION, disposition)
-        .body(Body::from(html))
+        .body(Body::from(body))
         .map_err(|err| AppError::Io(io::Error::new(io::ErrorKind::InvalidData, err)))?;
 
     Ok(response)
 }
+
+#[derive(Deserialize)]
+pub(crate) struct BundleQuery {
+    redact: Option<bool>,
+    /// `none`, `standard` or `strict`; an alternative to `redact`.
+    redaction: Option<String>,
+}
+
+/// Every mode in one ZIP; cached reports are reused and the rest are collected one mode at a time.
+pub async fn bundle_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<BundleQuery>,
+) -> Result<Response, AppError> {
+    let requested = requested_profile(query.redaction.as_deref(), query.redact)?;
+    // Roles lower than admin get the modes they may request; the manifest lists the rest.
+    let (allowed, skipped): (Vec<_>, Vec<_>) = InxiMode::ALL
+        .into_iter()
+        .partition(|mode| caller.role.allows_mode(*mode));
+    let deepest = allowed.last().copied().unwrap_or(InxiMode::Basic);
+    let redact = caller
+        .role
+        .authorize(deepest, requested.map(RedactionProfile::masks))?;
+    let options = ExportOptions {
+        redaction: granted_profile(requested, redact),
+        role: Some(caller.role),
+        ..ExportOptions::default()
+    };
+
+    // The bundle counts as one request, charged only when a mode misses the cache and runs inxi.
+    let mut charged = false;
+    let mut reports = Vec::with_capacity(allowed.len());
+    for mode in allowed {
+        if let Some(report) = service.cached(mode) {
+            reports.push(report);
+            continue;
+        }
+        if !charged {
+            limiter.check(&caller.client)?;
+            charged = true;
+        }
+        reports.push(service.run(mode).await?);
+    }
+    let body = bundle_zip(&reports, &skipped, &options)?;
+    let disposition = format!(
+        "attachment; filename=\"{}\"",
+        options.bundle_filename(&reports[0])
+    );
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/zip")
+        .header(header::CONTENT_DISPOSITION, disposition)
+        .body(Body::from(body))
+        .map_err(|err| AppError::Io(io::Error::new(io::ErrorKind::InvalidData, err)))
+}
+
+fn requested_profile(
+    redaction: Option<&str>,
+    redact: Option<bool>,
+) -> Result<Option<RedactionProfile>, AppError> {
+    match (redaction, redact) {
+        (Some(_), Some(_)) => Err(AppError::InvalidQuery(
+            "pass either 'redact' or 'redaction', not both".to_string(),
+        )),
+        (Some(profile), None) => RedactionProfile::parse(profile).map(Some),
+        (None, redact) => Ok(redact.map(RedactionProfile::from_redact)),
+    }
+}
+
+/// The requested profile when the role lets it apply, otherwise what the role enforces.
+fn granted_profile(requested: Option<RedactionProfile>, redact: bool) -> RedactionProfile {
+    requested
+        .filter(|_| redact)
+        .unwrap_or(RedactionProfile::from_redact(redact))
+}
+
+/// The held report behind `report={id}`, which pins the mode, so `mode` may not be given as well.
+pub(crate) fn held_report(
+    service: &InxiService,
+    id: &str,
+    mode_given: bool,
+) -> Result<SystemReport, AppError> {
+    if mode_given {
+        return Err(AppError::InvalidQuery(
+            "use either `report` or `mode`, not both".to_string(),
+        ));
+    }
+    let id = Uuid::parse_str(id)
+        .map_err(|_| AppError::InvalidQuery("`report` must be a report ID (UUID)".to_string()))?
+        .to_string();
+    service.held_report(&id).ok_or_else(|| {
+        AppError::NotFound(format!(
+            "report '{id}' is unknown or has expired; reload the report and download again"
+        ))
+    })
+}
+
+fn section_names(value: Option<&str>) -> Result<Vec<String>, AppError> {
+    value
+        .map(parse_section_names)
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
@@ -0,0 +1,41 @@
+// Responsibility: Serve liveness (/healthz, /api/v1/health) and readiness (/readyz) probes.
+// Design reasoning: Probes read cached service state and a PATH lookup only, so polling never spawns inxi.
+// Extension guidance: Fold new dependencies (providers, storage) into the readiness decision here.
+// Security considerations: Probes skip role checks and rate limiting, so they must never include report contents.
+
+use axum::{extract::Extension, http::StatusCode, response::Json};
+use std::sync::Arc;
+
+use crate::models::{HealthStatus, InxiBinaryStatus, ReadinessReport};
+use crate::services::InxiService;
+use crate::utils::find_in_path;
+
+pub async fn health_handler() -> Json<HealthStatus> {
+    Json(HealthStatus {
+        status: "ok",
+        version: env!("CARGO_PKG_VERSION"),
+    })
+}
+
+pub async fn readiness_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+) -> (StatusCode, Json<ReadinessReport>) {
+    let path = find_in_path("inxi");
+    let present = path.is_some();
+    let report = ReadinessReport {
+        status: if present { "ready" } else { "unavailable" },
+        inxi: InxiBinaryStatus {
+            present,
+            path: path.map(|path| path.display().to_string()),
+            version: service.version().map(str::to_string),
+        },
+        collections: service.status(),
+    };
+
+    let status = if present {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
@@ -0,0 +1,45 @@
+// Responsibility: Serve /metrics in Prometheus text format from the cached report and service counters.
+// Design reasoning: Scrapes go through the report cache, so a 15s scrape interval costs at most one inxi run per TTL.
+// Extension guidance: Change METRICS_MODE in config.rs to export deeper data; new gauges belong in services/metrics.rs.
+// Security considerations: Scrapes honour caller roles but skip the per-client rate limiter, relying on the cache instead.
+
+use axum::{
+    extract::Extension,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+use crate::config::METRICS_MODE;
+use crate::error::AppError;
+use crate::routes::caller::Caller;
+use crate::services::{InxiMode, InxiService, metrics};
+
+pub async fn metrics_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    caller: Caller,
+) -> Result<Response, AppError> {
+    let mode = InxiMode::parse(METRICS_MODE)?;
+    let redact = caller.role.authorize(mode, None)?;
+
+    let report = match service.run(mode).await {
+        Ok(mut report) => {
+            caller.role.restrict(&mut report, redact);
+            Some(report)
+        }
+        Err(err) => {
+            tracing::warn!(error = %err, "metrics scrape without fresh report");
+            None
+        }
+    };
+
+    let body = metrics::render(report.as_ref(), &service.mode_metrics());
+    Ok((
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        body,
+    )
+        .into_response())
+}
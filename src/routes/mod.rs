@@ -4,11 +4,34 @@
 // Security considerations: Each module sanitizes inputs and relies on AppError for consistent responses.
 
 pub mod api;
+pub mod caller;
 pub mod dashboard;
+pub mod diff;
 pub mod download;
+pub mod health;
+pub mod metrics;
+pub mod request_context;
+pub mod session;
+pub mod snapshots;
 pub mod static_files;
+pub mod stream;
+pub mod v1;
 
 pub use api::api_handler;
 pub use dashboard::dashboard_handler;
-pub use download::download_handler;
+pub use diff::diff_handler;
+pub use download::{bundle_handler, download_handler};
+pub use health::{health_handler, readiness_handler};
+pub use metrics::metrics_handler;
+pub use request_context::{fallback_handler, request_context};
+pub use session::{login_handler, logout_handler, session_handler};
+pub use snapshots::{
+    create_snapshot_handler, delete_snapshot_handler, snapshot_handler, snapshots_handler,
+    update_snapshot_handler,
+};
 pub use static_files::static_handler;
+pub use stream::stream_handler;
+pub use v1::{
+    card_png_handler, card_svg_handler, entries_handler, modes_handler, openapi_handler,
+    schema_handler, search_handler, section_handler, sections_handler,
+};
@@ -0,0 +1,133 @@
+// Responsibility: Assign every request an ID, run it inside a tracing span, and finish error responses (request ID, HTML for browsers).
+// Design reasoning: AppError cannot see the request, so it tags its response with ErrorDetails and this middleware renders the final body.
+// Extension guidance: Add per-request context (timings, client info) to the span here; handlers inherit it through tracing.
+// Security considerations: Incoming X-Request-Id values are accepted only when short and limited to [A-Za-z0-9._-], so logs cannot be forged.
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{HeaderMap, HeaderValue, Uri, header},
+    middleware::Next,
+    response::Response,
+};
+use std::time::Instant;
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::config::{REQUEST_ID_HEADER, REQUEST_ID_MAX_LEN};
+use crate::error::{AppError, ErrorDetails, ErrorResponse};
+use crate::rendering::error_page;
+
+pub async fn request_context(request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let wants_html = wants_html(request.uri(), request.headers());
+
+    let span = tracing::info_span!(
+        "request",
+        id = %id,
+        method = %request.method(),
+        path = %request.uri().path()
+    );
+    let started = Instant::now();
+    let mut response = next.run(request).instrument(span.clone()).await;
+
+    if let Some(details) = response.extensions().get::<ErrorDetails>().cloned() {
+        response = render_error(response, details, &id, wants_html);
+    }
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    span.in_scope(|| {
+        tracing::info!(
+            status = response.status().as_u16(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "request completed"
+        );
+    });
+    response
+}
+
+/// Answers unknown paths with a regular `not_found` error instead of an empty 404.
+pub async fn fallback_handler(uri: Uri) -> AppError {
+    AppError::NotFound(format!("route '{}'", uri.path()))
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= REQUEST_ID_MAX_LEN
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.'))
+}
+
+/// Browsers navigating to non-API pages get HTML errors; API clients and scripts keep JSON.
+fn wants_html(uri: &Uri, headers: &HeaderMap) -> bool {
+    !uri.path().starts_with("/api/")
+        && headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|accept| accept.contains("text/html"))
+}
+
+fn render_error(response: Response, details: ErrorDetails, id: &str, wants_html: bool) -> Response {
+    let (mut parts, _) = response.into_parts();
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    let (content_type, body) = if wants_html {
+        (
+            "text/html; charset=utf-8",
+            error_page(parts.status, details.code, &details.message, id),
+        )
+    } else {
+        let payload = ErrorResponse {
+            code: details.code.to_string(),
+            message: details.message,
+            request_id: Some(id.to_string()),
+        };
+        (
+            "application/json",
+            serde_json::to_string(&payload).unwrap_or_default(),
+        )
+    };
+
+    parts
+        .headers
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_request_id, wants_html};
+    use axum::http::{HeaderMap, HeaderValue, Uri, header};
+
+    #[test]
+    fn validates_ids_and_detects_browser_routes() {
+        assert!(is_valid_request_id("3f1c-req_42.a"));
+        assert!(!is_valid_request_id("id with spaces"));
+        assert!(!is_valid_request_id("id\nforged=1"));
+        assert!(!is_valid_request_id(&"a".repeat(65)));
+
+        let mut browser = HeaderMap::new();
+        browser.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("text/html,*/*;q=0.8"),
+        );
+        assert!(wants_html(
+            &Uri::from_static("/download?mode=full"),
+            &browser
+        ));
+        assert!(!wants_html(&Uri::from_static("/api/v1/system"), &browser));
+        assert!(!wants_html(
+            &Uri::from_static("/download"),
+            &HeaderMap::new()
+        ));
+    }
+}
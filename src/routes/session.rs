@@ -0,0 +1,66 @@
+// Responsibility: Serve /api/v1/session: report the caller's role and sign browsers in and out with a session cookie.
+// Design reasoning: Browsers cannot attach `Authorization` to EventSource or plain links, so a token is exchanged once for a cookie.
+// Extension guidance: Keep session state in AccessPolicy; new per-caller facts for the dashboard belong in SessionInfo.
+// Security considerations: Cookies are HttpOnly and SameSite=Strict, logins are rate limited, and the token is never echoed back.
+
+use axum::{
+    extract::{Extension, rejection::JsonRejection},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Json, Response},
+};
+use std::sync::Arc;
+
+use crate::config::{SESSION_COOKIE, SESSION_TTL};
+use crate::error::AppError;
+use crate::models::{LoginRequest, SessionInfo};
+use crate::routes::caller::{Caller, Credential, session_cookie};
+use crate::services::{AccessPolicy, RateLimiter, Role};
+
+pub async fn session_handler(
+    Extension(policy): Extension<Arc<AccessPolicy>>,
+    caller: Caller,
+) -> Json<SessionInfo> {
+    Json(session_info(&policy, caller.role, caller.credential))
+}
+
+pub async fn login_handler(
+    Extension(policy): Extension<Arc<AccessPolicy>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    body: Result<Json<LoginRequest>, JsonRejection>,
+) -> Result<Response, AppError> {
+    let Json(body) = body.map_err(|rejection| AppError::InvalidQuery(rejection.body_text()))?;
+    // Every attempt spends a token, which keeps guessing as slow as collecting reports.
+    limiter.check(&caller.client)?;
+    let (id, role) = policy.login(body.token.trim())?;
+    tracing::info!(role = %role, "browser session started");
+    let cookie = format!(
+        "{SESSION_COOKIE}={id}; Path=/; HttpOnly; SameSite=Strict; Max-Age={}",
+        SESSION_TTL.as_secs()
+    );
+    Ok((
+        [(header::SET_COOKIE, cookie)],
+        Json(session_info(&policy, role, Credential::Session)),
+    )
+        .into_response())
+}
+
+pub async fn logout_handler(
+    Extension(policy): Extension<Arc<AccessPolicy>>,
+    headers: HeaderMap,
+) -> Response {
+    if let Some(id) = session_cookie(&headers) {
+        policy.logout(id);
+    }
+    let cookie = format!("{SESSION_COOKIE}=; Path=/; HttpOnly; SameSite=Strict; Max-Age=0");
+    (StatusCode::NO_CONTENT, [(header::SET_COOKIE, cookie)]).into_response()
+}
+
+fn session_info(policy: &AccessPolicy, role: Role, credential: Credential) -> SessionInfo {
+    SessionInfo {
+        role: role.as_str(),
+        credential: credential.as_str(),
+        login_available: policy.token_count() > 0,
+        manages_snapshots: role.manages_snapshots(),
+    }
+}
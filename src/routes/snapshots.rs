@@ -0,0 +1,144 @@
+// Responsibility: Serve /api/v1/snapshots: list, save, view, annotate and delete stored reports.
+// Design reasoning: Handlers stay thin over SnapshotStore, whose file IO runs on the blocking pool; viewing reuses the live report checks.
+// Extension guidance: New snapshot operations go in SnapshotStore first, then get a handler here and a path item in openapi.rs.
+// Security considerations: Writes need a role that manages snapshots, and JSON bodies are required so browsers preflight cross-site posts.
+
+use axum::{
+    extract::{Extension, Path, Query, rejection::JsonRejection},
+    http::StatusCode,
+    response::Json,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::config::{DEFAULT_MODE, SNAPSHOT_PAGE_DEFAULT, SNAPSHOT_PAGE_MAX};
+use crate::error::AppError;
+use crate::models::{
+    Snapshot, SnapshotCreate, SnapshotPage, SnapshotSummary, SnapshotTrigger, SnapshotUpdate,
+};
+use crate::routes::caller::Caller;
+use crate::routes::download::held_report;
+use crate::services::{InxiMode, InxiService, RateLimiter, Role, SnapshotStore};
+
+#[derive(Deserialize)]
+pub(crate) struct SnapshotListQuery {
+    offset: Option<usize>,
+    limit: Option<usize>,
+    /// Case-insensitive substring of the label.
+    label: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SnapshotQuery {
+    redact: Option<bool>,
+}
+
+pub async fn snapshots_handler(
+    Extension(store): Extension<Arc<SnapshotStore>>,
+    _caller: Caller,
+    Query(query): Query<SnapshotListQuery>,
+) -> Result<Json<SnapshotPage>, AppError> {
+    let limit = query.limit.unwrap_or(SNAPSHOT_PAGE_DEFAULT);
+    if !(1..=SNAPSHOT_PAGE_MAX).contains(&limit) {
+        return Err(AppError::InvalidQuery(format!(
+            "`limit` must be between 1 and {SNAPSHOT_PAGE_MAX}"
+        )));
+    }
+    let label = query
+        .label
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty());
+    let offset = query.offset.unwrap_or(0);
+    store
+        .blocking(move |store| store.list(offset, limit, label.as_deref()))
+        .await
+        .map(Json)
+}
+
+/// Saves a report the server still holds (`report`) or a fresh collection of `mode`.
+pub async fn create_snapshot_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(store): Extension<Arc<SnapshotStore>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    body: Result<Json<SnapshotCreate>, JsonRejection>,
+) -> Result<(StatusCode, Json<SnapshotSummary>), AppError> {
+    let Json(body) = body.map_err(|rejection| AppError::InvalidQuery(rejection.body_text()))?;
+    ensure_manager(caller.role)?;
+    let held = body
+        .report
+        .as_deref()
+        .map(|id| held_report(&service, id, body.mode.is_some()))
+        .transpose()?;
+    let mode = held
+        .as_ref()
+        .map_or(body.mode.as_deref().unwrap_or(DEFAULT_MODE), |report| {
+            report.mode.as_str()
+        });
+    let final_mode = InxiMode::parse(mode)?;
+    caller.role.authorize(final_mode, None)?;
+    let report = match held {
+        Some(report) => report,
+        None => {
+            limiter.check(&caller.client)?;
+            service.run(final_mode).await?
+        }
+    };
+
+    let saved = store
+        .blocking(move |store| store.save(report, SnapshotTrigger::Manual, body.label, body.notes))
+        .await?;
+    tracing::info!(id = %saved.id, mode = %saved.mode, "saved snapshot");
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+/// The stored report as the caller's role would see it live: restricted sections dropped, masked unless allowed.
+pub async fn snapshot_handler(
+    Extension(store): Extension<Arc<SnapshotStore>>,
+    caller: Caller,
+    Path(id): Path<String>,
+    Query(query): Query<SnapshotQuery>,
+) -> Result<Json<Snapshot>, AppError> {
+    let mut snapshot = store.blocking(move |store| store.get(&id)).await?;
+    let redact = caller
+        .role
+        .authorize(InxiMode::parse(&snapshot.report.mode)?, query.redact)?;
+    caller.role.restrict(&mut snapshot.report, redact);
+    Ok(Json(snapshot))
+}
+
+pub async fn update_snapshot_handler(
+    Extension(store): Extension<Arc<SnapshotStore>>,
+    caller: Caller,
+    Path(id): Path<String>,
+    body: Result<Json<SnapshotUpdate>, JsonRejection>,
+) -> Result<Json<SnapshotSummary>, AppError> {
+    let Json(update) = body.map_err(|rejection| AppError::InvalidQuery(rejection.body_text()))?;
+    ensure_manager(caller.role)?;
+    store
+        .blocking(move |store| store.update(&id, update))
+        .await
+        .map(Json)
+}
+
+pub async fn delete_snapshot_handler(
+    Extension(store): Extension<Arc<SnapshotStore>>,
+    caller: Caller,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    ensure_manager(caller.role)?;
+    let deleted = id.clone();
+    store.blocking(move |store| store.delete(&deleted)).await?;
+    tracing::info!(id = %id, "deleted snapshot");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn ensure_manager(role: Role) -> Result<(), AppError> {
+    if role.manages_snapshots() {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(format!(
+            "role '{role}' may not manage snapshots"
+        )))
+    }
+}
@@ -1,16 +1,20 @@
 // Responsibility: Serve embedded assets at /static/* while avoiding filesystem access at runtime.
-// Design reasoning: This handler delegates to generated asset map to keep runtime I/O minimal.
-// Extension guidance: Extend caching headers here if you later support versioned assets.
+// Design reasoning: This handler delegates to generated asset map to keep runtime I/O minimal; build.rs precomputes hashes and compressed variants.
+// Extension guidance: Reference assets through their fingerprinted path so pages pick up new builds despite immutable caching.
 // Security considerations: Only known paths are served; any unknown path returns a controlled 404.
 
-use crate::config::STATIC_PREFIX;
+use crate::config::{ASSET_IMMUTABLE_CACHE_CONTROL, ASSET_REVALIDATE_CACHE_CONTROL, STATIC_PREFIX};
 use crate::error::AppError;
-use crate::generated_assets::{self, AssetContent};
+use crate::generated_assets::{self, Asset, AssetContent};
 use axum::body::{Body, Bytes};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
 use axum::{extract::Path, http::header, response::Response};
 use std::io;
 
-pub async fn static_handler(Path(path): Path<String>) -> Result<Response, AppError> {
+pub async fn static_handler(
+    Path(path): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     let trimmed = path.trim_start_matches('/');
     let normalized = if trimmed.is_empty() {
         STATIC_PREFIX.to_string()
@@ -18,17 +22,125 @@ pub async fn static_handler(Path(path): Path<String>) -> Result<Response, AppErr
         format!("{}/{}", STATIC_PREFIX.trim_end_matches('/'), trimmed)
     };
 
-    if let Some((content_type, content)) = generated_assets::get_asset(&normalized) {
-        let body = match content {
-            AssetContent::Text(value) => Body::from(value),
-            AssetContent::Binary(bytes) => Body::from(Bytes::from_static(bytes)),
-        };
-        let response = Response::builder()
-            .header(header::CONTENT_TYPE, content_type)
-            .body(body)
-            .map_err(|err| AppError::Io(io::Error::new(io::ErrorKind::Other, err)))?;
-        Ok(response)
+    let Some(asset) = generated_assets::get_asset(&normalized) else {
+        return Err(AppError::AssetNotFound(normalized));
+    };
+
+    // Fingerprinted URLs change whenever the content does, so they can be cached forever.
+    let cache_control = if normalized == asset.fingerprinted_path {
+        ASSET_IMMUTABLE_CACHE_CONTROL
     } else {
-        Err(AppError::AssetNotFound(normalized))
+        ASSET_REVALIDATE_CACHE_CONTROL
+    };
+
+    let accept_encoding = headers
+        .get(header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("");
+    let (encoding, body) = select_body(asset, accept_encoding);
+    let etag = encoded_etag(asset.etag, encoding);
+
+    let builder = Response::builder()
+        .header(header::ETAG, &etag)
+        .header(header::CACHE_CONTROL, cache_control)
+        .header(header::VARY, "Accept-Encoding");
+
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok());
+    if if_none_match.is_some_and(|value| etag_matches(value, &etag)) {
+        return builder
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Body::empty())
+            .map_err(|err| AppError::Io(io::Error::other(err)));
+    }
+
+    let mut builder = builder.header(header::CONTENT_TYPE, asset.content_type);
+    if let Some(encoding) = encoding {
+        builder = builder.header(header::CONTENT_ENCODING, HeaderValue::from_static(encoding));
+    }
+    builder
+        .body(body)
+        .map_err(|err| AppError::Io(io::Error::other(err)))
+}
+
+fn select_body(asset: &'static Asset, accept_encoding: &str) -> (Option<&'static str>, Body) {
+    if let Some(bytes) = asset.brotli
+        && accepts_encoding(accept_encoding, "br")
+    {
+        return (Some("br"), Body::from(Bytes::from_static(bytes)));
+    }
+    if let Some(bytes) = asset.gzip
+        && accepts_encoding(accept_encoding, "gzip")
+    {
+        return (Some("gzip"), Body::from(Bytes::from_static(bytes)));
+    }
+    let body = match asset.content {
+        AssetContent::Text(value) => Body::from(value),
+        AssetContent::Binary(bytes) => Body::from(Bytes::from_static(bytes)),
+    };
+    (None, body)
+}
+
+/// Each encoding is a separate representation, so it needs its own strong validator: `"abc"` becomes `"abc-br"`.
+fn encoded_etag(etag: &str, encoding: Option<&str>) -> String {
+    match encoding {
+        Some(encoding) => {
+            let suffix = if encoding == "gzip" { "gz" } else { encoding };
+            format!("{}-{suffix}\"", etag.trim_end_matches('"'))
+        }
+        None => etag.to_string(),
+    }
+}
+
+/// True when `If-None-Match` lists the asset's ETag (weak comparison) or `*`.
+fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+/// True when `Accept-Encoding` lists `coding` (or `*`) without `q=0`.
+fn accepts_encoding(accept_encoding: &str, coding: &str) -> bool {
+    let mut wildcard = false;
+    for item in accept_encoding.split(',') {
+        let mut parts = item.split(';').map(str::trim);
+        let name = parts.next().unwrap_or("");
+        let enabled = parts
+            .filter_map(|param| param.strip_prefix("q="))
+            .all(|quality| quality.parse::<f32>().map_or(true, |q| q > 0.0));
+        if name.eq_ignore_ascii_case(coding) {
+            return enabled;
+        }
+        if name == "*" {
+            wildcard = enabled;
+        }
+    }
+    wildcard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{accepts_encoding, encoded_etag, etag_matches};
+
+    #[test]
+    fn negotiates_conditional_and_encoded_requests() {
+        assert!(etag_matches("\"abc\"", "\"abc\""));
+        assert!(etag_matches("\"old\", W/\"abc\"", "\"abc\""));
+        assert!(etag_matches("*", "\"abc\""));
+        assert!(!etag_matches("\"old\"", "\"abc\""));
+        assert_eq!(encoded_etag("\"abc\"", Some("br")), "\"abc-br\"");
+        assert_eq!(encoded_etag("\"abc\"", Some("gzip")), "\"abc-gz\"");
+        assert!(!etag_matches(
+            &encoded_etag("\"abc\"", Some("br")),
+            "\"abc\""
+        ));
+
+        assert!(accepts_encoding("gzip, deflate, br", "br"));
+        assert!(accepts_encoding("gzip;q=0.5", "gzip"));
+        assert!(!accepts_encoding("br;q=0, gzip", "br"));
+        assert!(accepts_encoding("*", "br"));
+        assert!(!accepts_encoding("identity", "gzip"));
     }
 }
@@ -0,0 +1,107 @@
+// Responsibility: Push fresh reports to dashboards over Server-Sent Events on a server-side schedule.
+// Design reasoning: SSE rides plain HTTP and EventSource reconnects on its own; the report cache dedupes collections across subscribers.
+// Extension guidance: Add event types (e.g. alerts) next to `report`/`patch`; the dashboard ignores events it does not know.
+// Security considerations: Authorization and rate limiting happen once at connect time, and every pushed report is role-restricted.
+
+use axum::{
+    extract::{Extension, Query},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::stream::{self, Stream};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::{convert::Infallible, io, sync::Arc};
+use tokio::time::{Interval, MissedTickBehavior};
+
+use crate::config::{DEFAULT_MODE, STREAM_INTERVAL};
+use crate::error::AppError;
+use crate::routes::caller::Caller;
+use crate::services::{InxiMode, InxiService, RateLimiter, Role};
+use crate::utils::json_patch;
+
+#[derive(Deserialize)]
+pub(crate) struct StreamQuery {
+    mode: Option<String>,
+    redact: Option<bool>,
+    /// Send JSON-patch deltas after the first full report (default) instead of full reports every tick.
+    delta: Option<bool>,
+}
+
+struct StreamState {
+    service: Arc<InxiService>,
+    role: Role,
+    mode: InxiMode,
+    redact: bool,
+    delta: bool,
+    interval: Interval,
+    previous: Option<Value>,
+}
+
+pub async fn stream_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<StreamQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let mode = InxiMode::parse(query.mode.as_deref().unwrap_or(DEFAULT_MODE))?;
+    let redact = caller.role.authorize(mode, query.redact)?;
+    limiter.check(&caller.client)?;
+    tracing::info!(mode = %mode, role = %caller.role, "report stream opened");
+
+    let mut interval = tokio::time::interval(STREAM_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let state = StreamState {
+        service,
+        role: caller.role,
+        mode,
+        redact,
+        delta: query.delta.unwrap_or(true),
+        interval,
+        previous: None,
+    };
+
+    let events = stream::unfold(state, |mut state| async move {
+        // Ending the stream on shutdown lets graceful shutdown drain this connection.
+        tokio::select! {
+            _ = state.interval.tick() => {}
+            _ = state.service.shutting_down() => return None,
+        }
+        let event = next_event(&mut state).await;
+        Some((Ok(event), state))
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+async fn next_event(state: &mut StreamState) -> Event {
+    let mut report = match state.service.run(state.mode).await {
+        Ok(report) => report,
+        Err(err) => return error_event(&err),
+    };
+    state.role.restrict(&mut report, state.redact);
+
+    let current = match serde_json::to_value(&report) {
+        Ok(value) => value,
+        Err(err) => return error_event(&AppError::Io(io::Error::other(err))),
+    };
+
+    let event = match state.previous.as_ref().filter(|_| state.delta) {
+        // `base` lets a client that replaced its report in between detect the mismatch and resync.
+        Some(previous) => Event::default().event("patch").json_data(json!({
+            "base": previous["id"],
+            "operations": json_patch::diff(previous, &current),
+        })),
+        None => Event::default().event("report").json_data(&current),
+    };
+    state.previous = Some(current);
+    event.unwrap_or_default()
+}
+
+fn error_event(err: &AppError) -> Event {
+    tracing::warn!(code = err.code(), error = %err, "report stream collection failed");
+    Event::default()
+        .event("error")
+        .json_data(json!({ "code": err.code(), "message": err.public_message() }))
+        .unwrap_or_default()
+}
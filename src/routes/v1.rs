@@ -0,0 +1,204 @@
+// Responsibility: Serve the auxiliary /api/v1 endpoints (section and entry lookups, spec cards, modes, OpenAPI and JSON Schema).
+// Design reasoning: Versioned handlers reuse the same collection path as /api/v1/system so behaviour never diverges.
+// Extension guidance: Add new v1 endpoints here and describe them in services/openapi.rs in the same change.
+// Security considerations: Mode listings reflect the caller's role, and report-derived endpoints go through the same authorization.
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::header,
+    response::{IntoResponse, Json},
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::error::AppError;
+use crate::models::{EntryMatch, ModeInfo, SearchResults, SectionSummary, SystemSection};
+use crate::rendering::{card_png, card_svg, theme};
+use crate::routes::api::{ModeQuery, collect_report};
+use crate::routes::caller::Caller;
+use crate::services::search::{self, SearchFilter};
+use crate::services::{InxiMode, InxiService, RateLimiter, lookup, openapi, schema};
+
+pub async fn sections_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<ModeQuery>,
+) -> Result<Json<Vec<SectionSummary>>, AppError> {
+    let report = collect_report(&service, &limiter, &caller, &query).await?;
+    let summaries = report
+        .sections
+        .into_iter()
+        .map(|section| SectionSummary {
+            title: section.title,
+            entries: section.entries.len(),
+        })
+        .collect();
+    Ok(Json(summaries))
+}
+
+pub async fn section_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Path(title): Path<String>,
+    Query(query): Query<ModeQuery>,
+) -> Result<Json<Vec<SystemSection>>, AppError> {
+    if !caller.role.allows_section(&title) {
+        return Err(AppError::Forbidden(format!(
+            "role '{}' may not view section '{title}'",
+            caller.role
+        )));
+    }
+
+    let report = collect_report(&service, &limiter, &caller, &query).await?;
+    let sections = lookup::sections_titled(report, &title);
+    if sections.is_empty() {
+        return Err(AppError::NotFound(format!("section '{title}'")));
+    }
+    Ok(Json(sections))
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EntryQuery {
+    key: Option<String>,
+    section: Option<String>,
+    mode: Option<String>,
+    redact: Option<bool>,
+}
+
+pub async fn entries_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<EntryQuery>,
+) -> Result<Json<Vec<EntryMatch>>, AppError> {
+    let key = query
+        .key
+        .as_deref()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| AppError::InvalidQuery("'key' parameter is required".to_string()))?;
+
+    let mode_query = ModeQuery {
+        mode: query.mode.clone(),
+        redact: query.redact,
+    };
+    let report = collect_report(&service, &limiter, &caller, &mode_query).await?;
+    let matches = lookup::entries_keyed(&report, key, query.section.as_deref());
+    if matches.is_empty() {
+        return Err(AppError::NotFound(format!("entries with key '{key}'")));
+    }
+    Ok(Json(matches))
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SearchQuery {
+    q: Option<String>,
+    key: Option<String>,
+    section: Option<String>,
+    /// Treat `q` and `key` as regular expressions.
+    regex: Option<bool>,
+    mode: Option<String>,
+    redact: Option<bool>,
+}
+
+pub async fn search_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<SearchResults>, AppError> {
+    // Compile first so a malformed pattern is rejected without spawning inxi.
+    let filter = SearchFilter::new(
+        query.q.as_deref(),
+        query.key.as_deref(),
+        query.section.as_deref(),
+        query.regex.unwrap_or(false),
+    )?;
+
+    let mode_query = ModeQuery {
+        mode: query.mode,
+        redact: query.redact,
+    };
+    let report = collect_report(&service, &limiter, &caller, &mode_query).await?;
+    Ok(Json(search::search(&report, &filter)))
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CardQuery {
+    mode: Option<String>,
+    redact: Option<bool>,
+    /// Dashboard theme whose palette colours the card (default `default`).
+    theme: Option<String>,
+}
+
+impl CardQuery {
+    fn palette(&self) -> Result<&'static theme::Palette, AppError> {
+        let name = self.theme.as_deref().unwrap_or("default");
+        theme::palette(name)
+            .ok_or_else(|| AppError::InvalidQuery(format!("unknown theme '{name}'")))
+    }
+
+    fn mode_query(&self) -> ModeQuery {
+        ModeQuery {
+            mode: self.mode.clone(),
+            redact: self.redact,
+        }
+    }
+}
+
+pub async fn card_svg_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<CardQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let palette = query.palette()?;
+    let report = collect_report(&service, &limiter, &caller, &query.mode_query()).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "image/svg+xml")],
+        card_svg(&report, palette),
+    ))
+}
+
+pub async fn card_png_handler(
+    Extension(service): Extension<Arc<InxiService>>,
+    Extension(limiter): Extension<Arc<RateLimiter>>,
+    caller: Caller,
+    Query(query): Query<CardQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let palette = query.palette()?;
+    let report = collect_report(&service, &limiter, &caller, &query.mode_query()).await?;
+    let svg = card_svg(&report, palette);
+    // Rasterizing takes tens of milliseconds of CPU; keep it off the async workers.
+    let png = tokio::task::spawn_blocking(move || card_png(&svg))
+        .await
+        .map_err(|err| AppError::Io(std::io::Error::other(err)))??;
+    Ok(([(header::CONTENT_TYPE, "image/png")], png))
+}
+
+pub async fn modes_handler(caller: Caller) -> Json<Vec<ModeInfo>> {
+    let modes = InxiMode::ALL
+        .iter()
+        .map(|mode| ModeInfo {
+            name: mode.as_str(),
+            description: mode.description(),
+            args: mode.args(),
+            allowed: caller.role.allows_mode(*mode),
+        })
+        .collect();
+    Json(modes)
+}
+
+pub async fn openapi_handler() -> Json<Value> {
+    Json(openapi::document())
+}
+
+pub async fn schema_handler() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/schema+json")],
+        Json(schema::system_report_schema()),
+    )
+}
@@ -0,0 +1,280 @@
+// Responsibility: Map callers to roles and decide which modes, sections, and unredacted fields each role may see.
+// Design reasoning: A small ordered role enum keeps authorization checks declarative and shared by every report route.
+// Extension guidance: Add roles or tighten RESTRICTED_SECTIONS here; handlers only call `authorize` and `restrict`.
+// Security considerations: Tokens are opaque, never logged, and unknown ones are rejected; browser sessions are random IDs mapped to tokens in memory.
+
+use crate::config::{ACCESS_TOKENS_ENV, ANONYMOUS_ROLE_ENV, SESSION_MAX, SESSION_TTL};
+use crate::error::AppError;
+use crate::models::SystemReport;
+use crate::services::InxiMode;
+use crate::services::redaction;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Sections hidden from viewers because they describe network identity rather than hardware specs.
+const RESTRICTED_SECTIONS: &[&str] = &["network", "bluetooth"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Viewer,
+    Operator,
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Operator => "operator",
+            Role::Admin => "admin",
+        }
+    }
+
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "viewer" => Some(Role::Viewer),
+            "operator" => Some(Role::Operator),
+            "admin" => Some(Role::Admin),
+            _ => None,
+        }
+    }
+
+    /// Most detailed inxi mode this role may request.
+    pub fn max_mode(&self) -> InxiMode {
+        match self {
+            Role::Viewer => InxiMode::Full,
+            Role::Operator => InxiMode::Verbose,
+            Role::Admin => InxiMode::Maximum,
+        }
+    }
+
+    pub fn allows_mode(&self, mode: InxiMode) -> bool {
+        mode <= self.max_mode()
+    }
+
+    pub fn allows_section(&self, title: &str) -> bool {
+        *self >= Role::Operator
+            || !RESTRICTED_SECTIONS.contains(&title.trim().to_lowercase().as_str())
+    }
+
+    pub fn allows_unredacted(&self) -> bool {
+        *self == Role::Admin
+    }
+
+    /// Saving, editing and deleting snapshots; every role may list and view them.
+    pub fn manages_snapshots(&self) -> bool {
+        *self >= Role::Operator
+    }
+
+    /// Validates a request and returns whether the resulting report must be redacted.
+    pub fn authorize(&self, mode: InxiMode, redact: Option<bool>) -> Result<bool, AppError> {
+        if !self.allows_mode(mode) {
+            return Err(AppError::Forbidden(format!(
+                "role '{self}' may not request mode '{mode}'"
+            )));
+        }
+
+        match redact {
+            Some(false) if !self.allows_unredacted() => Err(AppError::Forbidden(format!(
+                "role '{self}' may not request unredacted reports"
+            ))),
+            Some(value) => Ok(value),
+            None => Ok(!self.allows_unredacted()),
+        }
+    }
+
+    /// Drops sections this role may not see and masks identifying fields when requested.
+    pub fn restrict(&self, report: &mut SystemReport, redact: bool) {
+        report
+            .sections
+            .retain(|section| self.allows_section(&section.title));
+        if redact {
+            redaction::redact_report(report);
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug)]
+pub struct AccessPolicy {
+    tokens: HashMap<String, Role>,
+    anonymous: Role,
+    /// Browser sessions by ID, each standing in for the token it was created from.
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+#[derive(Debug)]
+struct Session {
+    token: String,
+    expires: Instant,
+}
+
+impl AccessPolicy {
+    /// Reads `role:token` pairs from the environment. Without tokens every caller is an admin,
+    /// preserving the single-user localhost behaviour; once tokens exist anonymous callers default to viewer.
+    pub fn from_env() -> Result<Self, AppError> {
+        let tokens = std::env::var(ACCESS_TOKENS_ENV).unwrap_or_default();
+        let anonymous = std::env::var(ANONYMOUS_ROLE_ENV).ok();
+        Self::from_parts(&tokens, anonymous.as_deref())
+    }
+
+    fn from_parts(tokens: &str, anonymous: Option<&str>) -> Result<Self, AppError> {
+        let mut map = HashMap::new();
+        for pair in tokens
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let (role, token) = pair.split_once(':').ok_or_else(|| {
+                AppError::Config(format!("{ACCESS_TOKENS_ENV} entries must be 'role:token'"))
+            })?;
+            let role = Role::parse(role)
+                .ok_or_else(|| AppError::Config(format!("unknown role '{}'", role.trim())))?;
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(AppError::Config(format!(
+                    "{ACCESS_TOKENS_ENV} contains an empty token for role '{role}'"
+                )));
+            }
+            map.insert(token.to_string(), role);
+        }
+
+        let anonymous = match anonymous {
+            Some(value) => Role::parse(value)
+                .ok_or_else(|| AppError::Config(format!("unknown role '{}'", value.trim())))?,
+            None if map.is_empty() => Role::Admin,
+            None => Role::Viewer,
+        };
+
+        Ok(Self {
+            tokens: map,
+            anonymous,
+            sessions: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn token_count(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn anonymous_role(&self) -> Role {
+        self.anonymous
+    }
+
+    /// Exchanges a valid token for a new session ID, to be sent back as an HttpOnly cookie.
+    pub fn login(&self, token: &str) -> Result<(String, Role), AppError> {
+        self.login_at(token, Instant::now())
+    }
+
+    fn login_at(&self, token: &str, now: Instant) -> Result<(String, Role), AppError> {
+        let role = self.resolve(Some(token))?;
+        let id = Uuid::new_v4().simple().to_string();
+        let mut sessions = self.sessions.lock().expect("session lock poisoned");
+        sessions.retain(|_, session| session.expires > now);
+        if sessions.len() >= SESSION_MAX
+            && let Some(oldest) = sessions
+                .iter()
+                .min_by_key(|(_, session)| session.expires)
+                .map(|(id, _)| id.clone())
+        {
+            sessions.remove(&oldest);
+        }
+        sessions.insert(
+            id.clone(),
+            Session {
+                token: token.to_string(),
+                expires: now + SESSION_TTL,
+            },
+        );
+        Ok((id, role))
+    }
+
+    /// Token behind a live session; unknown or expired IDs yield `None` so the caller falls back to anonymous.
+    pub fn session_token(&self, id: &str) -> Option<String> {
+        self.session_token_at(id, Instant::now())
+    }
+
+    fn session_token_at(&self, id: &str, now: Instant) -> Option<String> {
+        let sessions = self.sessions.lock().expect("session lock poisoned");
+        sessions
+            .get(id)
+            .filter(|session| session.expires > now)
+            .map(|session| session.token.clone())
+    }
+
+    pub fn logout(&self, id: &str) {
+        self.sessions
+            .lock()
+            .expect("session lock poisoned")
+            .remove(id);
+    }
+
+    pub fn resolve(&self, token: Option<&str>) -> Result<Role, AppError> {
+        match token {
+            None => Ok(self.anonymous),
+            Some(token) => self
+                .tokens
+                .get(token)
+                .copied()
+                .ok_or_else(|| AppError::Forbidden("unrecognized access token".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessPolicy, Role};
+    use crate::config::SESSION_TTL;
+    use crate::services::InxiMode;
+    use std::time::Instant;
+
+    #[test]
+    fn viewer_is_limited_to_redacted_full_reports() {
+        assert!(
+            Role::Viewer
+                .authorize(InxiMode::Full, None)
+                .expect("allowed")
+        );
+        assert!(Role::Viewer.authorize(InxiMode::Maximum, None).is_err());
+        assert!(
+            Role::Viewer
+                .authorize(InxiMode::Basic, Some(false))
+                .is_err()
+        );
+        assert!(
+            !Role::Admin
+                .authorize(InxiMode::Maximum, None)
+                .expect("allowed")
+        );
+        assert!(!Role::Viewer.manages_snapshots());
+        assert!(Role::Operator.manages_snapshots());
+    }
+
+    #[test]
+    fn anonymous_role_depends_on_configured_tokens() {
+        let open = AccessPolicy::from_parts("", None).expect("policy");
+        assert_eq!(open.resolve(None).expect("role"), Role::Admin);
+
+        let locked = AccessPolicy::from_parts("admin:s3cret, operator:ops", None).expect("policy");
+        assert_eq!(locked.resolve(None).expect("role"), Role::Viewer);
+        assert_eq!(locked.resolve(Some("ops")).expect("role"), Role::Operator);
+        assert!(locked.resolve(Some("nope")).is_err());
+
+        let now = Instant::now();
+        let (id, role) = locked.login_at("ops", now).expect("session");
+        assert_eq!(role, Role::Operator);
+        assert_eq!(locked.session_token_at(&id, now).as_deref(), Some("ops"));
+        assert_eq!(locked.session_token_at(&id, now + SESSION_TTL), None);
+        assert!(locked.login_at("nope", now).is_err());
+        locked.logout(&id);
+        assert_eq!(locked.session_token_at(&id, now), None);
+    }
+}
@@ -0,0 +1,409 @@
+// Responsibility: Compare two SystemReports section by section and entry by entry, skipping fields matched by ignore rules.
+// Design reasoning: Entries are matched by device IDs where inxi numbers them (`Device-1`), so added hardware does not shift every later entry.
+// Extension guidance: Tune volatile readings in `config::DIFF_VOLATILE_RULES` and device identities in `DEVICE_ID_LABELS`.
+// Security considerations: Rules are length-checked plain names compared case-insensitively; no patterns are compiled from input.
+
+use std::collections::HashMap;
+
+use crate::config::{DIFF_MAX_RULES, DIFF_RULE_MAX_CHARS, DIFF_VOLATILE_RULES};
+use crate::error::AppError;
+use crate::models::{
+    ChangeKind, DiffSide, DiffSummary, EntryChange, FieldChange, ReportDiff, SectionDiff,
+    SystemEntry, SystemReport,
+};
+use crate::services::lookup;
+use crate::services::redaction::REDACTED;
+use crate::utils::labelled_fields;
+
+/// Inline fields that identify a numbered device entry, most specific first; the leading text is the fallback.
+const DEVICE_ID_LABELS: &[&str] = &["chip-ID", "serial", "bus-ID"];
+
+/// Fields and sections left out of a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffRules {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    text: String,
+    /// Lowercased section title, or `None` for every section.
+    section: Option<String>,
+    /// Lowercased field name, or `*` for the whole section.
+    field: String,
+}
+
+impl DiffRules {
+    /// The built-in volatile rules (when `volatile`) followed by `extra` rules.
+    pub fn new(volatile: bool, extra: &[String]) -> Result<Self, AppError> {
+        if extra.len() > DIFF_MAX_RULES {
+            return Err(AppError::InvalidQuery(format!(
+                "at most {DIFF_MAX_RULES} ignore rules are allowed"
+            )));
+        }
+        let builtin: &[&str] = if volatile { DIFF_VOLATILE_RULES } else { &[] };
+        let rules = builtin
+            .iter()
+            .copied()
+            .chain(extra.iter().map(String::as_str))
+            .map(Rule::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rules })
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.rules.iter().map(|rule| rule.text.clone()).collect()
+    }
+
+    fn ignores_section(&self, title: &str) -> bool {
+        let title = title.to_lowercase();
+        self.rules
+            .iter()
+            .any(|rule| rule.field == "*" && rule.section.as_deref() == Some(title.as_str()))
+    }
+
+    fn ignores_field(&self, section: &str, name: &str) -> bool {
+        let section = section.to_lowercase();
+        let name = name.to_lowercase();
+        self.rules.iter().any(|rule| {
+            rule.field == name && rule.section.as_deref().is_none_or(|title| title == section)
+        })
+    }
+}
+
+impl Rule {
+    /// `field`, `Section:field` or `Section:*`.
+    fn parse(text: &str) -> Result<Self, AppError> {
+        let text = text.trim();
+        let invalid =
+            |reason: &str| AppError::InvalidQuery(format!("ignore rule '{text}' {reason}"));
+        if text.chars().count() > DIFF_RULE_MAX_CHARS {
+            return Err(invalid(&format!(
+                "is longer than {DIFF_RULE_MAX_CHARS} characters"
+            )));
+        }
+        if text.chars().any(char::is_control) {
+            return Err(invalid("contains control characters"));
+        }
+        let (section, field) = match text.split_once(':') {
+            Some((section, field)) => (Some(section.trim()), field.trim()),
+            None => (None, text),
+        };
+        if field.is_empty() || section.is_some_and(str::is_empty) {
+            return Err(invalid("must be `field`, `Section:field` or `Section:*`"));
+        }
+        if field == "*" && section.is_none() {
+            return Err(invalid("needs a section, e.g. `Sensors:*`"));
+        }
+        Ok(Self {
+            text: text.to_string(),
+            section: section.map(str::to_lowercase),
+            field: field.to_lowercase(),
+        })
+    }
+}
+
+/// Changes from `old` to `new`; sections follow the newer report, with removed sections last.
+pub fn diff_reports(old: &SystemReport, new: &SystemReport, rules: &DiffRules) -> ReportDiff {
+    let mut summary = DiffSummary::default();
+    let mut sections = Vec::new();
+    let old_sections = keyed(&old.sections, |section| section.title.to_lowercase());
+    let new_sections = keyed(&new.sections, |section| section.title.to_lowercase());
+    let mut matched = vec![false; old_sections.len()];
+
+    for (identity, section) in &new_sections {
+        let previous = old_sections.iter().position(|(id, _)| id == identity);
+        if let Some(index) = previous {
+            matched[index] = true;
+        }
+        if rules.ignores_section(&section.title) {
+            continue;
+        }
+        let (kind, old_entries) = match previous {
+            Some(index) => (
+                ChangeKind::Modified,
+                old_sections[index].1.entries.as_slice(),
+            ),
+            None => (ChangeKind::Added, &[][..]),
+        };
+        let changes = diff_entries(
+            &section.title,
+            old_entries,
+            &section.entries,
+            rules,
+            &mut summary,
+        );
+        if !changes.is_empty() {
+            sections.push(SectionDiff {
+                title: section.title.clone(),
+                kind,
+                changes,
+            });
+        }
+    }
+
+    for ((_, section), _) in old_sections
+        .iter()
+        .zip(&matched)
+        .filter(|(_, matched)| !**matched)
+    {
+        if rules.ignores_section(&section.title) {
+            continue;
+        }
+        let changes = diff_entries(&section.title, &section.entries, &[], rules, &mut summary);
+        if !changes.is_empty() {
+            sections.push(SectionDiff {
+                title: section.title.clone(),
+                kind: ChangeKind::Removed,
+                changes,
+            });
+        }
+    }
+
+    ReportDiff {
+        old: side(old),
+        new: side(new),
+        rules: rules.names(),
+        summary,
+        sections,
+    }
+}
+
+fn diff_entries(
+    title: &str,
+    old: &[SystemEntry],
+    new: &[SystemEntry],
+    rules: &DiffRules,
+    summary: &mut DiffSummary,
+) -> Vec<EntryChange> {
+    let old_entries = keyed(old, entry_identity);
+    let new_entries = keyed(new, entry_identity);
+    let mut matched = vec![false; old_entries.len()];
+    let mut changes = Vec::new();
+
+    for (identity, entry) in &new_entries {
+        let new_fields = compared_fields(title, entry, rules);
+        let Some(index) = old_entries.iter().position(|(id, _)| id == identity) else {
+            if new_fields.is_empty() {
+                summary.ignored += 1;
+                continue;
+            }
+            summary.added += 1;
+            changes.push(EntryChange {
+                key: entry.key.clone(),
+                kind: ChangeKind::Added,
+                old: None,
+                new: Some(entry.value.clone()),
+                fields: Vec::new(),
+            });
+            continue;
+        };
+        matched[index] = true;
+        let previous = old_entries[index].1;
+        if previous.value == entry.value {
+            continue;
+        }
+        let fields = field_changes(&compared_fields(title, previous, rules), &new_fields);
+        if fields.is_empty() {
+            summary.ignored += 1;
+            continue;
+        }
+        summary.modified += 1;
+        changes.push(EntryChange {
+            key: entry.key.clone(),
+            kind: ChangeKind::Modified,
+            old: Some(previous.value.clone()),
+            new: Some(entry.value.clone()),
+            fields,
+        });
+    }
+
+    for ((_, entry), _) in old_entries
+        .iter()
+        .zip(&matched)
+        .filter(|(_, matched)| !**matched)
+    {
+        if compared_fields(title, entry, rules).is_empty() {
+            summary.ignored += 1;
+            continue;
+        }
+        summary.removed += 1;
+        changes.push(EntryChange {
+            key: entry.key.clone(),
+            kind: ChangeKind::Removed,
+            old: Some(entry.value.clone()),
+            new: None,
+            fields: Vec::new(),
+        });
+    }
+    changes
+}
+
+fn field_changes(old: &[(String, String)], new: &[(String, String)]) -> Vec<FieldChange> {
+    let old_fields = keyed(old, |(name, _)| name.to_lowercase());
+    let new_fields = keyed(new, |(name, _)| name.to_lowercase());
+    let mut matched = vec![false; old_fields.len()];
+    let mut changes = Vec::new();
+
+    for (identity, (name, value)) in &new_fields {
+        let previous = old_fields.iter().position(|(id, _)| id == identity);
+        if let Some(index) = previous {
+            matched[index] = true;
+        }
+        let old_value = previous.map(|index| &old_fields[index].1.1);
+        if old_value != Some(value) {
+            changes.push(FieldChange {
+                name: name.clone(),
+                old: old_value.cloned(),
+                new: Some(value.clone()),
+            });
+        }
+    }
+    for ((_, (name, value)), _) in old_fields
+        .iter()
+        .zip(&matched)
+        .filter(|(_, matched)| !**matched)
+    {
+        changes.push(FieldChange {
+            name: name.clone(),
+            old: Some(value.clone()),
+            new: None,
+        });
+    }
+    changes
+}
+
+/// The `(name, value)` fields of an entry that the rules keep; the entry key names its leading text.
+fn compared_fields(section: &str, entry: &SystemEntry, rules: &DiffRules) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for field in labelled_fields(&entry.value) {
+        match field.label {
+            // inxi continues lists such as `cores: 1: 3400 2: 3400` with numbered labels.
+            Some(label) if label.chars().all(|ch| ch.is_ascii_digit()) && !fields.is_empty() => {
+                if let Some((_, value)) = fields.last_mut() {
+                    *value = format!("{value} {label}: {}", field.value)
+                        .trim_start()
+                        .to_string();
+                }
+            }
+            Some(label) => fields.push((label, field.value)),
+            None => fields.push((entry.key.clone(), field.value)),
+        }
+    }
+    fields.retain(|(name, _)| !rules.ignores_field(section, name));
+    fields
+}
+
+/// Numbered keys (`Device-2`, `ID-1`) are renumbered when hardware changes, so they match by device ID instead.
+fn entry_identity(entry: &SystemEntry) -> String {
+    let key = entry.key.to_lowercase();
+    let Some(base) = key
+        .rsplit_once('-')
+        .filter(|(_, number)| !number.is_empty() && number.chars().all(|ch| ch.is_ascii_digit()))
+        .map(|(base, _)| base)
+    else {
+        return key;
+    };
+
+    let fields = labelled_fields(&entry.value);
+    let usable = |value: &str| !value.is_empty() && value != REDACTED;
+    DEVICE_ID_LABELS
+        .iter()
+        .find_map(|label| {
+            fields
+                .iter()
+                .find(|field| {
+                    field
+                        .label
+                        .as_deref()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(label))
+                        && usable(&field.value)
+                })
+                .map(|field| format!("{base} {label} {}", field.value.to_lowercase()))
+        })
+        .or_else(|| {
+            fields
+                .first()
+                .filter(|field| field.label.is_none() && usable(&field.value))
+                .map(|field| format!("{base} {}", field.value.to_lowercase()))
+        })
+        .unwrap_or(key)
+}
+
+/// Pairs items with `identity#n`, where `n` counts earlier items with the same identity.
+fn keyed<T>(items: &[T], identity: impl Fn(&T) -> String) -> Vec<(String, &T)> {
+    let mut seen = HashMap::<String, usize>::new();
+    items
+        .iter()
+        .map(|item| {
+            let identity = identity(item);
+            let count = seen.entry(identity.clone()).or_default();
+            *count += 1;
+            (format!("{identity}#{count}"), item)
+        })
+        .collect()
+}
+
+fn side(report: &SystemReport) -> DiffSide {
+    DiffSide {
+        id: report.id.clone(),
+        mode: report.mode.clone(),
+        hostname: lookup::hostname(report),
+        collected_utc: report.metadata.collected_utc.clone(),
+        schema_version: report.schema_version.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffRules, diff_reports};
+    use crate::models::ChangeKind;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn matches_devices_by_id_and_skips_volatile_fields() {
+        let old = parse_system_report(
+            "System:\n  Host: box Kernel: 6.12.68 arch: x86_64\nGraphics:\n  Device-1: AMD Picasso driver: amdgpu chip-ID: 1002:15d8\nDrives:\n  ID-1: /dev/nvme0n1 model: SSD 970 temp: 41.9 C\nSensors:\n  System Temperatures: cpu: 45.0 C\n",
+            InxiMode::Full,
+        )
+        .expect("old");
+        let new = parse_system_report(
+            "System:\n  Host: box Kernel: 6.13.1 arch: x86_64\nGraphics:\n  Device-1: NVIDIA AD104 driver: nvidia chip-ID: 10de:2786\n  Device-2: AMD Picasso driver: amdgpu chip-ID: 1002:15d8\nDrives:\n  ID-1: /dev/nvme0n1 model: SSD 970 temp: 48.0 C\nSensors:\n  System Temperatures: cpu: 60.0 C\n",
+            InxiMode::Full,
+        )
+        .expect("new");
+
+        let diff = diff_reports(&old, &new, &DiffRules::new(true, &[]).expect("rules"));
+        assert_eq!(
+            (
+                diff.summary.added,
+                diff.summary.removed,
+                diff.summary.modified,
+                diff.summary.ignored
+            ),
+            (1, 0, 1, 1)
+        );
+        assert_eq!(diff.sections.len(), 2);
+        let kernel = &diff.sections[0].changes[0];
+        assert_eq!(kernel.kind, ChangeKind::Modified);
+        assert_eq!(kernel.fields[0].name, "Kernel");
+        assert_eq!(kernel.fields[0].new.as_deref(), Some("6.13.1"));
+        let gpu = &diff.sections[1].changes;
+        assert_eq!(
+            (gpu.len(), gpu[0].key.as_str(), gpu[0].kind),
+            (1, "Device-1", ChangeKind::Added)
+        );
+
+        let extra = ["Graphics:*".to_string()];
+        let everything = diff_reports(&old, &new, &DiffRules::new(false, &extra).expect("rules"));
+        let titles = everything
+            .sections
+            .iter()
+            .map(|section| section.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, ["System", "Drives", "Sensors"]);
+
+        assert!(DiffRules::new(false, &["*".to_string()]).is_err());
+        assert!(DiffRules::new(false, &["CPU:".to_string()]).is_err());
+    }
+}
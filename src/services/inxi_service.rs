@@ -1,16 +1,28 @@
 // Responsibility: Encapsulate how we invoke inxi with allowed modes and ensure its output is sanitized.
-// Design reasoning: This service isolates platform calls so we can swap mocking or extend caching without touching routes.
+// Design reasoning: This service isolates platform calls, caches reports per mode, and records collection outcomes for probes.
 // Extension guidance: Add buffering, caching, or new modes by keeping the interface unchanged and expanding the mode enum.
-// Security considerations: No user input is forwarded directly to the shell—modes map to fixed argument lists and ANSI sequences are stripped later.
+// Security considerations: No user input is forwarded directly to the shell—modes map to fixed argument lists and ANSI sequences are stripped later; a semaphore caps concurrent inxi processes and aborted collections kill inxi's whole process group.
 
+use crate::config::{
+    INXI_QUEUE_TIMEOUT, MAX_CONCURRENT_INXI, REPORT_CACHE_TTL, REPORT_RETENTION,
+    REPORT_RETENTION_MAX,
+};
 use crate::error::AppError;
-use crate::models::SystemReport;
-use crate::services::parser;
+use crate::models::{CacheState, CollectionFailure, CollectionRecord, ServiceStatus, SystemReport};
+use crate::services::metrics::ModeMetrics;
+use crate::services::{parser, provenance};
 use crate::utils::strip_ansi;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::process::Command;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum InxiMode {
     Basic,
     Full,
@@ -19,6 +31,13 @@ pub enum InxiMode {
 }
 
 impl InxiMode {
+    pub const ALL: [InxiMode; 4] = [
+        InxiMode::Basic,
+        InxiMode::Full,
+        InxiMode::Verbose,
+        InxiMode::Maximum,
+    ];
+
     pub fn args(&self) -> &'static [&'static str] {
         match self {
             InxiMode::Basic => &["-F"],
@@ -37,6 +56,15 @@ impl InxiMode {
         }
     }
 
+    pub fn description(&self) -> &'static str {
+        match self {
+            InxiMode::Basic => "Standard overview",
+            InxiMode::Full => "Extended output",
+            InxiMode::Verbose => "Detailed hardware info",
+            InxiMode::Maximum => "Maximum verbosity",
+        }
+    }
+
     pub fn parse(input: &str) -> Result<Self, AppError> {
         let normalized = input.trim().to_lowercase();
         match normalized.as_str() {
@@ -55,21 +83,223 @@ impl fmt::Display for InxiMode {
     }
 }
 
+#[derive(Debug)]
+struct CachedReport {
+    report: SystemReport,
+    collected: Instant,
+}
+
+#[derive(Debug, Default)]
+struct ServiceState {
+    cache: HashMap<InxiMode, CachedReport>,
+    /// Recent reports by collection order, kept for `/download?report={id}`.
+    held: VecDeque<CachedReport>,
+    last_success: Option<CollectionRecord>,
+    last_failure: Option<CollectionFailure>,
+    metrics: HashMap<InxiMode, ModeMetrics>,
+}
+
 #[derive(Clone, Debug)]
-pub struct InxiService;
+pub struct InxiService {
+    permits: Arc<Semaphore>,
+    state: Arc<Mutex<ServiceState>>,
+    version: Option<String>,
+    /// Keyed machine ID hash, read once at startup for report metadata.
+    machine_id: Option<String>,
+    /// Cancelled when shutdown begins: no new inxi runs start and live streams end.
+    shutdown: CancellationToken,
+    /// Cancelled when the drain timeout expires: running inxi children are killed.
+    abort: CancellationToken,
+    running: Arc<AtomicUsize>,
+}
+
+/// Counts a running inxi child for the lifetime of one collection.
+struct RunningGuard(Arc<AtomicUsize>);
+
+impl RunningGuard {
+    fn new(running: &Arc<AtomicUsize>) -> Self {
+        running.fetch_add(1, Ordering::SeqCst);
+        Self(Arc::clone(running))
+    }
+}
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Kills the process group inxi leads when a collection is abandoned, so helpers it spawned
+/// (lspci, dmidecode, ...) die with it; `kill_on_drop` alone only reaches inxi itself.
+struct ProcessGroup(Option<i32>);
+
+impl ProcessGroup {
+    /// Called once inxi has exited on its own, after which the group ID may be reused.
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        if let Some(pgid) = self.0 {
+            // SAFETY: kill(2) takes plain integers and has no memory-safety preconditions;
+            // the negative ID addresses the group created by `process_group(0)` at spawn.
+            unsafe {
+                libc::kill(-pgid, libc::SIGKILL);
+            }
+        }
+    }
+}
 
 impl InxiService {
-    pub fn new() -> Self {
-        Self
+    /// Creates the service; `version` is the `inxi --version` line detected at startup.
+    pub fn new(version: Option<String>) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(MAX_CONCURRENT_INXI)),
+            state: Arc::new(Mutex::new(ServiceState::default())),
+            version,
+            machine_id: provenance::machine_id_hash(),
+            shutdown: CancellationToken::new(),
+            abort: CancellationToken::new(),
+            running: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Stops new collections; cached reports are still served while requests drain.
+    pub fn begin_shutdown(&self) {
+        self.shutdown.cancel();
+    }
+
+    /// Resolves once shutdown has begun.
+    pub async fn shutting_down(&self) {
+        self.shutdown.cancelled().await;
+    }
+
+    /// Kills every running inxi child and returns how many were running.
+    pub fn abort_collections(&self) -> usize {
+        self.shutdown.cancel();
+        self.abort.cancel();
+        self.running_collections()
+    }
+
+    pub fn running_collections(&self) -> usize {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Snapshot of collection history and cache contents, cheap enough for frequent probes.
+    pub fn status(&self) -> ServiceStatus {
+        let state = self.lock_state();
+        let mut cache = state
+            .cache
+            .iter()
+            .map(|(mode, cached)| CacheState {
+                mode: mode.as_str().to_string(),
+                age_secs: cached.collected.elapsed().as_secs(),
+                fresh: cached.collected.elapsed() < REPORT_CACHE_TTL,
+            })
+            .collect::<Vec<_>>();
+        cache.sort_by(|a, b| a.mode.cmp(&b.mode));
+
+        ServiceStatus {
+            last_success: state.last_success.clone(),
+            last_failure: state.last_failure.clone(),
+            cache_ttl_secs: REPORT_CACHE_TTL.as_secs(),
+            cache,
+        }
+    }
+
+    /// Per-mode run, failure, and cache-hit counters for the metrics exporter.
+    pub fn mode_metrics(&self) -> Vec<(InxiMode, ModeMetrics)> {
+        let state = self.lock_state();
+        let mut metrics = state
+            .metrics
+            .iter()
+            .map(|(mode, metrics)| (*mode, metrics.clone()))
+            .collect::<Vec<_>>();
+        metrics.sort_by_key(|(mode, _)| *mode);
+        metrics
+    }
+
+    /// A recently collected report by its ID, exactly as it was first served.
+    pub fn held_report(&self, id: &str) -> Option<SystemReport> {
+        let mut state = self.lock_state();
+        prune_held(&mut state.held);
+        state
+            .held
+            .iter()
+            .find(|held| held.report.id == id)
+            .map(|held| held.report.clone())
     }
 
     pub async fn run(&self, mode: InxiMode) -> Result<SystemReport, AppError> {
+        if let Some(report) = self.cached(mode) {
+            return Ok(report);
+        }
+
+        let _permit = tokio::time::timeout(INXI_QUEUE_TIMEOUT, self.permits.acquire())
+            .await
+            .map_err(|_| {
+                tracing::warn!(mode = %mode, "inxi concurrency limit reached");
+                AppError::RateLimited {
+                    retry_after: INXI_QUEUE_TIMEOUT.as_secs().max(1),
+                }
+            })?
+            .map_err(|err| AppError::CommandFailure(err.to_string()))?;
+
+        // Another request may have filled the cache while this one waited for a permit.
+        if let Some(report) = self.cached(mode) {
+            return Ok(report);
+        }
+        if self.shutdown.is_cancelled() {
+            return Err(AppError::ShuttingDown);
+        }
+
+        let started = Instant::now();
+        let result = self.collect(mode).await.map(|mut report| {
+            let metadata = &mut report.metadata;
+            metadata.machine_id_hash = self.machine_id.clone();
+            metadata.collection_ms = Some(started.elapsed().as_millis() as u64);
+            if let Some(version) = self.version.as_deref() {
+                metadata.inxi_version = provenance::inxi_version_number(version);
+            }
+            report
+        });
+        // Collections cut short by shutdown say nothing about inxi's health, so probes and metrics skip them.
+        if !matches!(result, Err(AppError::ShuttingDown)) {
+            self.record(mode, started.elapsed(), &result);
+        }
+        result
+    }
+
+    async fn collect(&self, mode: InxiMode) -> Result<SystemReport, AppError> {
         tracing::info!(command = "inxi", mode = %mode, args = ?mode.args(), "running inxi");
-        let output = Command::new("inxi")
+        let _running = RunningGuard::new(&self.running);
+        let mut command = Command::new("inxi");
+        // inxi leads its own process group, so abandoning the collection (abort or a dropped
+        // request) kills everything it started instead of orphaning it.
+        command
             .args(mode.args())
-            .output()
-            .await
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .process_group(0)
+            .kill_on_drop(true);
+        let child = command
+            .spawn()
             .map_err(|err| AppError::CommandFailure(err.to_string()))?;
+        let group = ProcessGroup(child.id().and_then(|pid| i32::try_from(pid).ok()));
+        let output = tokio::select! {
+            output = child.wait_with_output() => output.map_err(|err| AppError::CommandFailure(err.to_string()))?,
+            _ = self.abort.cancelled() => {
+                tracing::warn!(mode = %mode, "killing running inxi for shutdown");
+                return Err(AppError::ShuttingDown);
+            }
+        };
+        group.disarm();
 
         if !output.status.success() {
             return Err(AppError::CommandFailure(
@@ -81,4 +311,110 @@ impl InxiService {
         let cleaned = strip_ansi(&raw);
         parser::parse_system_report(&cleaned, mode)
     }
+
+    /// The cached report for `mode` while it is younger than the cache TTL; never runs `inxi`.
+    pub fn cached(&self, mode: InxiMode) -> Option<SystemReport> {
+        let mut state = self.lock_state();
+        let cached = state.cache.get(&mode)?;
+        if cached.collected.elapsed() >= REPORT_CACHE_TTL {
+            return None;
+        }
+        let report = cached.report.clone();
+        state.metrics.entry(mode).or_default().cache_hits += 1;
+        tracing::debug!(mode = %mode, "serving cached report");
+        Some(report)
+    }
+
+    fn record(&self, mode: InxiMode, elapsed: Duration, result: &Result<SystemReport, AppError>) {
+        let mut state = self.lock_state();
+        let now = unix_now();
+        let metrics = state.metrics.entry(mode).or_default();
+        metrics.observe(elapsed.as_secs_f64());
+        if result.is_err() {
+            metrics.failures += 1;
+        }
+
+        match result {
+            Ok(report) => {
+                state.held.push_back(CachedReport {
+                    report: report.clone(),
+                    collected: Instant::now(),
+                });
+                prune_held(&mut state.held);
+                state.cache.insert(
+                    mode,
+                    CachedReport {
+                        report: report.clone(),
+                        collected: Instant::now(),
+                    },
+                );
+                state.last_success = Some(CollectionRecord {
+                    mode: mode.as_str().to_string(),
+                    finished_at: now,
+                    duration_ms: elapsed.as_millis() as u64,
+                });
+            }
+            Err(err) => {
+                tracing::warn!(mode = %mode, error = %err, "inxi collection failed");
+                state.last_failure = Some(CollectionFailure {
+                    mode: mode.as_str().to_string(),
+                    failed_at: now,
+                    code: err.code(),
+                    error: err.public_message(),
+                });
+            }
+        }
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, ServiceState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn prune_held(held: &mut VecDeque<CachedReport>) {
+    while held.len() > REPORT_RETENTION_MAX
+        || held
+            .front()
+            .is_some_and(|oldest| oldest.collected.elapsed() >= REPORT_RETENTION)
+    {
+        held.pop_front();
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InxiMode, InxiService};
+    use crate::config::REPORT_RETENTION_MAX;
+    use crate::services::parser::parse_system_report;
+    use std::time::Duration;
+
+    #[test]
+    fn holds_recent_reports_by_id() {
+        let service = InxiService::new(None);
+        let ids = (0..=REPORT_RETENTION_MAX)
+            .map(|_| {
+                let report =
+                    parse_system_report("System:\n  Host: box\n", InxiMode::Full).expect("report");
+                let id = report.id.clone();
+                service.record(InxiMode::Full, Duration::from_millis(5), &Ok(report));
+                id
+            })
+            .collect::<Vec<_>>();
+
+        assert!(service.held_report(&ids[0]).is_none());
+        let latest = service
+            .held_report(&ids[REPORT_RETENTION_MAX])
+            .expect("held");
+        assert_eq!(latest.mode, "full");
+        assert!(service.held_report("not-an-id").is_none());
+    }
 }
@@ -0,0 +1,269 @@
+// Responsibility: Answer targeted questions about a parsed report (one section, one key) without shipping the whole report.
+// Design reasoning: Lookups run over the same SystemSection list the full API returns, so results never disagree with it.
+// Extension guidance: Add alias tables (e.g. "os" -> "Distro") here rather than in handlers.
+// Security considerations: Matching is plain case-insensitive comparison; no user-supplied patterns are compiled.
+
+use crate::models::{EntryMatch, SystemReport, SystemSection};
+use crate::services::metrics::parse_bytes;
+use crate::utils::labelled_fields;
+
+/// All sections whose title equals `title` ignoring case; inxi may repeat titles across devices.
+pub fn sections_titled(report: SystemReport, title: &str) -> Vec<SystemSection> {
+    let wanted = title.trim().to_lowercase();
+    report
+        .sections
+        .into_iter()
+        .filter(|section| section.title.to_lowercase() == wanted)
+        .collect()
+}
+
+/// Finds `key` either as an entry key or as an inline `key:` field inside entry values.
+pub fn entries_keyed(report: &SystemReport, key: &str, section: Option<&str>) -> Vec<EntryMatch> {
+    let wanted = key.trim().to_lowercase();
+    let section = section.map(|title| title.trim().to_lowercase());
+    let mut matches = Vec::new();
+
+    for current in &report.sections {
+        if section
+            .as_deref()
+            .is_some_and(|title| current.title.to_lowercase() != title)
+        {
+            continue;
+        }
+
+        for entry in &current.entries {
+            let fields = labelled_fields(&entry.value);
+            if entry.key.to_lowercase() == wanted {
+                let leading = fields
+                    .first()
+                    .filter(|field| field.label.is_none())
+                    .map(|field| field.value.clone())
+                    .unwrap_or_else(|| entry.value.clone());
+                matches.push(EntryMatch {
+                    section: current.title.clone(),
+                    entry: entry.key.clone(),
+                    key: entry.key.clone(),
+                    value: leading,
+                });
+            }
+
+            for field in fields {
+                let Some(label) = field.label else {
+                    continue;
+                };
+                if label.to_lowercase() == wanted {
+                    matches.push(EntryMatch {
+                        section: current.title.clone(),
+                        entry: entry.key.clone(),
+                        key: label,
+                        value: field.value,
+                    });
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+/// Headline facts (distro, kernel, CPU, GPU, memory, storage) for export summaries; missing facts are skipped.
+pub fn key_facts(report: &SystemReport) -> Vec<(&'static str, String)> {
+    const FACTS: &[(&str, &[(&str, &str)])] = &[
+        ("Distro", &[("Distro", "System")]),
+        ("Kernel", &[("Kernel", "System")]),
+        ("CPU", &[("model", "CPU"), ("Info", "CPU")]),
+        ("GPU", &[("Device-1", "Graphics")]),
+        (
+            "Memory",
+            &[("total", "Info"), ("Memory", "Info"), ("RAM", "Memory")],
+        ),
+        ("Storage", &[("total", "Drives"), ("size", "Drives")]),
+    ];
+
+    FACTS
+        .iter()
+        .filter_map(|(label, candidates)| {
+            candidates.iter().find_map(|(key, section)| {
+                entries_keyed(report, key, Some(section))
+                    .into_iter()
+                    .map(|found| found.value)
+                    .find(|value| !value.is_empty())
+                    .map(|value| (*label, value))
+            })
+        })
+        .collect()
+}
+
+/// Report metadata rows (host, machine ID, versions, times) for export headers; distro and kernel are left to `key_facts`.
+pub fn provenance(report: &SystemReport) -> Vec<(&'static str, String)> {
+    let metadata = &report.metadata;
+    [
+        ("Host", metadata.hostname.clone()),
+        ("Machine ID", metadata.machine_id_hash.clone()),
+        ("inxi", metadata.inxi_version.clone()),
+        ("inxi-dash", Some(metadata.inxi_dash_version.clone())),
+        ("Collected (UTC)", Some(metadata.collected_utc.clone())),
+        ("Collected (local)", Some(metadata.collected_local.clone())),
+        (
+            "Collection time",
+            metadata
+                .collection_ms
+                .map(|ms| format!("{:.2} s", ms as f64 / 1000.0)),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(label, value)| {
+        value
+            .filter(|value| !value.is_empty())
+            .map(|value| (label, value))
+    })
+    .collect()
+}
+
+/// Host name from the System section (`<filter>` once redacted).
+pub fn hostname(report: &SystemReport) -> Option<String> {
+    first_value(report, "Host", "System")
+}
+
+/// Typed per-machine facts for inventory spreadsheets; sizes are in bytes.
+#[derive(Debug, Default, PartialEq)]
+pub struct Inventory {
+    pub hostname: Option<String>,
+    pub distro: Option<String>,
+    pub kernel: Option<String>,
+    pub cpu_model: Option<String>,
+    pub cpu_cores: Option<u32>,
+    pub gpu: Option<String>,
+    pub memory_total: Option<f64>,
+    pub disks: Vec<Disk>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Disk {
+    pub device: String,
+    pub model: Option<String>,
+    pub size: Option<f64>,
+}
+
+pub fn inventory(report: &SystemReport) -> Inventory {
+    let mut disks = Vec::new();
+    for section in &report.sections {
+        if !section.title.eq_ignore_ascii_case("drives") {
+            continue;
+        }
+        for entry in &section.entries {
+            if !entry.key.to_lowercase().starts_with("id-") {
+                continue;
+            }
+            let fields = labelled_fields(&entry.value);
+            let field = |label: &str| {
+                fields
+                    .iter()
+                    .find(|field| field.label.as_deref() == Some(label))
+                    .map(|field| field.value.clone())
+            };
+            let device = fields
+                .first()
+                .filter(|field| field.label.is_none())
+                .map_or_else(|| entry.key.clone(), |field| field.value.clone());
+            disks.push(Disk {
+                device,
+                model: field("model"),
+                size: field("size").as_deref().and_then(parse_bytes),
+            });
+        }
+    }
+
+    Inventory {
+        hostname: hostname(report),
+        distro: first_value(report, "Distro", "System"),
+        kernel: first_value(report, "Kernel", "System")
+            .and_then(|kernel| kernel.split_whitespace().next().map(str::to_string)),
+        cpu_model: first_value(report, "model", "CPU"),
+        cpu_cores: first_value(report, "Info", "CPU").and_then(|info| core_count(&info)),
+        gpu: first_value(report, "Device-1", "Graphics"),
+        memory_total: [("total", "Info"), ("total", "Memory")]
+            .iter()
+            .find_map(|(key, section)| first_value(report, key, section))
+            .as_deref()
+            .and_then(parse_bytes),
+        disks,
+    }
+}
+
+fn first_value(report: &SystemReport, key: &str, section: &str) -> Option<String> {
+    entries_keyed(report, key, Some(section))
+        .into_iter()
+        .map(|found| found.value)
+        .find(|value| !value.is_empty())
+}
+
+/// Reads inxi's core summary (`quad core`, `6-core`, `single core`) from the leading text of `CPU: Info`.
+fn core_count(info: &str) -> Option<u32> {
+    let word = info.split_whitespace().next()?.to_lowercase();
+    match word.as_str() {
+        "single" => Some(1),
+        "dual" => Some(2),
+        "triple" => Some(3),
+        "quad" => Some(4),
+        other => other.strip_suffix("-core")?.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{entries_keyed, inventory, key_facts, sections_titled};
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    const SAMPLE: &str = "System:\n  Host: box Kernel: 6.12.68 arch: x86_64\nDrives:\n  ID-1: /dev/nvme0n1 size: 476 GiB\nDrives:\n  ID-2: /dev/sda size: 1 TiB\n";
+
+    #[test]
+    fn finds_inline_fields_case_insensitively() {
+        let report = parse_system_report(SAMPLE, InxiMode::Basic).expect("report");
+        let matches = entries_keyed(&report, "kernel", None);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].section, "System");
+        assert_eq!(matches[0].entry, "Host");
+        assert_eq!(matches[0].value, "6.12.68");
+
+        let host = entries_keyed(&report, "host", Some("system"));
+        assert_eq!(host[0].value, "box");
+    }
+
+    #[test]
+    fn extracts_headline_facts() {
+        let report = parse_system_report(SAMPLE, InxiMode::Basic).expect("report");
+        assert_eq!(
+            key_facts(&report),
+            vec![
+                ("Kernel", "6.12.68".to_string()),
+                ("Storage", "476 GiB".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_typed_inventory() {
+        let raw = format!(
+            "{SAMPLE}CPU:\n  Info: 6-core model: AMD Ryzen 5 5600X bits: 64\nInfo:\n  Memory: total: 16 GiB used: 4 GiB\n"
+        );
+        let report = parse_system_report(&raw, InxiMode::Basic).expect("report");
+        let inventory = inventory(&report);
+
+        assert_eq!(inventory.hostname.as_deref(), Some("box"));
+        assert_eq!(inventory.cpu_cores, Some(6));
+        assert_eq!(
+            inventory.memory_total,
+            Some(16.0 * 1024.0 * 1024.0 * 1024.0)
+        );
+        assert_eq!(inventory.disks.len(), 2);
+        assert_eq!(inventory.disks[1].device, "/dev/sda");
+        assert_eq!(inventory.disks[1].size, Some(1024f64.powi(4)));
+    }
+
+    #[test]
+    fn returns_every_section_sharing_a_title() {
+        let report = parse_system_report(SAMPLE, InxiMode::Basic).expect("report");
+        assert_eq!(sections_titled(report, "DRIVES").len(), 2);
+    }
+}
@@ -0,0 +1,565 @@
+// Responsibility: Render Prometheus text exposition from a parsed report plus inxi-dash service internals.
+// Design reasoning: Values are extracted from the same labelled inxi fields the API exposes, so metrics never drift from reports.
+// Extension guidance: Add new hardware gauges to `hardware_samples` and describe them in FAMILIES; internals live in ModeMetrics.
+// Security considerations: Label values are escaped per the exposition format, and only numeric or info-style facts are exported.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::models::SystemReport;
+use crate::services::InxiMode;
+use crate::utils::labelled_fields;
+
+/// Upper bounds (seconds) of the inxi collection duration histogram.
+pub const DURATION_BUCKETS: [f64; 8] = [0.1, 0.25, 0.5, 1.0, 2.0, 5.0, 10.0, 30.0];
+
+/// Per-mode counters maintained by InxiService.
+#[derive(Clone, Debug, Default)]
+pub struct ModeMetrics {
+    pub buckets: [u64; DURATION_BUCKETS.len()],
+    pub duration_sum: f64,
+    pub runs: u64,
+    pub failures: u64,
+    pub cache_hits: u64,
+}
+
+impl ModeMetrics {
+    pub fn observe(&mut self, seconds: f64) {
+        for (bound, count) in DURATION_BUCKETS.iter().zip(self.buckets.iter_mut()) {
+            if seconds <= *bound {
+                *count += 1;
+            }
+        }
+        self.duration_sum += seconds;
+        self.runs += 1;
+    }
+}
+
+struct Family {
+    name: &'static str,
+    kind: &'static str,
+    help: &'static str,
+}
+
+const FAMILIES: &[Family] = &[
+    Family {
+        name: "inxi_temperature_celsius",
+        kind: "gauge",
+        help: "Temperature reported by inxi sensors or drives.",
+    },
+    Family {
+        name: "inxi_fan_speed_rpm",
+        kind: "gauge",
+        help: "Fan speed reported by inxi sensors.",
+    },
+    Family {
+        name: "inxi_memory_total_bytes",
+        kind: "gauge",
+        help: "Total system memory.",
+    },
+    Family {
+        name: "inxi_memory_used_bytes",
+        kind: "gauge",
+        help: "Used system memory.",
+    },
+    Family {
+        name: "inxi_disk_total_bytes",
+        kind: "gauge",
+        help: "Total local storage capacity.",
+    },
+    Family {
+        name: "inxi_disk_used_bytes",
+        kind: "gauge",
+        help: "Used local storage.",
+    },
+    Family {
+        name: "inxi_disk_used_percent",
+        kind: "gauge",
+        help: "Used local storage as a percentage of capacity.",
+    },
+    Family {
+        name: "inxi_drive_size_bytes",
+        kind: "gauge",
+        help: "Size of an individual drive.",
+    },
+    Family {
+        name: "inxi_battery_charge_percent",
+        kind: "gauge",
+        help: "Battery charge as a percentage of its current capacity.",
+    },
+    Family {
+        name: "inxi_cpu_speed_mhz",
+        kind: "gauge",
+        help: "CPU clock speed by CPU index; core=\"avg\" is the average across cores.",
+    },
+    Family {
+        name: "inxi_cpu_speed_limit_mhz",
+        kind: "gauge",
+        help: "Minimum and maximum CPU clock speed by CPU index.",
+    },
+    Family {
+        name: "inxi_kernel_info",
+        kind: "gauge",
+        help: "Running kernel release, always 1.",
+    },
+    Family {
+        name: "inxi_distro_info",
+        kind: "gauge",
+        help: "Linux distribution, always 1.",
+    },
+    Family {
+        name: "inxi_cpu_info",
+        kind: "gauge",
+        help: "CPU model, always 1.",
+    },
+];
+
+type CounterFn = fn(&ModeMetrics) -> u64;
+
+struct Sample {
+    labels: Vec<(&'static str, String)>,
+    value: f64,
+}
+
+/// Renders hardware gauges from `report` (when collection succeeded) followed by service internals.
+pub fn render(report: Option<&SystemReport>, modes: &[(InxiMode, ModeMetrics)]) -> String {
+    let mut out = String::new();
+
+    if let Some(report) = report {
+        let samples = hardware_samples(report);
+        for family in FAMILIES {
+            let Some(values) = samples.get(family.name) else {
+                continue;
+            };
+            write_header(&mut out, family.name, family.kind, family.help);
+            for sample in values {
+                write_sample(&mut out, family.name, &sample.labels, sample.value);
+            }
+        }
+    }
+
+    write_header(
+        &mut out,
+        "inxi_dash_scrape_success",
+        "gauge",
+        "Whether the report backing this scrape was collected successfully.",
+    );
+    write_sample(
+        &mut out,
+        "inxi_dash_scrape_success",
+        &[],
+        if report.is_some() { 1.0 } else { 0.0 },
+    );
+
+    write_header(
+        &mut out,
+        "inxi_dash_collection_duration_seconds",
+        "histogram",
+        "Duration of inxi executions by mode.",
+    );
+    for (mode, metrics) in modes {
+        let mode_label = ("mode", mode.as_str().to_string());
+        for (bound, count) in DURATION_BUCKETS.iter().zip(metrics.buckets.iter()) {
+            write_sample(
+                &mut out,
+                "inxi_dash_collection_duration_seconds_bucket",
+                &[mode_label.clone(), ("le", bound.to_string())],
+                *count as f64,
+            );
+        }
+        write_sample(
+            &mut out,
+            "inxi_dash_collection_duration_seconds_bucket",
+            &[mode_label.clone(), ("le", "+Inf".to_string())],
+            metrics.runs as f64,
+        );
+        write_sample(
+            &mut out,
+            "inxi_dash_collection_duration_seconds_sum",
+            std::slice::from_ref(&mode_label),
+            metrics.duration_sum,
+        );
+        write_sample(
+            &mut out,
+            "inxi_dash_collection_duration_seconds_count",
+            std::slice::from_ref(&mode_label),
+            metrics.runs as f64,
+        );
+    }
+
+    let counters: [(&str, &str, CounterFn); 2] = [
+        (
+            "inxi_dash_collection_failures_total",
+            "Failed inxi executions by mode.",
+            |metrics| metrics.failures,
+        ),
+        (
+            "inxi_dash_cache_hits_total",
+            "Reports served from the per-mode cache instead of running inxi.",
+            |metrics| metrics.cache_hits,
+        ),
+    ];
+    for (name, help, value) in counters {
+        write_header(&mut out, name, "counter", help);
+        for (mode, metrics) in modes {
+            write_sample(
+                &mut out,
+                name,
+                &[("mode", mode.as_str().to_string())],
+                value(metrics) as f64,
+            );
+        }
+    }
+
+    out
+}
+
+/// Prometheus rejects a scrape that repeats a series, so only the first sample per name and labels is kept.
+fn hardware_samples(report: &SystemReport) -> BTreeMap<&'static str, Vec<Sample>> {
+    let mut samples: BTreeMap<&'static str, Vec<Sample>> = BTreeMap::new();
+    let mut push = |name: &'static str, labels: Vec<(&'static str, String)>, value: f64| {
+        let series = samples.entry(name).or_default();
+        if !series.iter().any(|sample| sample.labels == labels) {
+            series.push(Sample { labels, value });
+        }
+    };
+
+    // `Info: Memory` and `Memory: RAM` report the same totals; the Info line is present in every mode.
+    let memory_title = if report.sections.iter().any(|section| {
+        section.title.eq_ignore_ascii_case("info")
+            && section
+                .entries
+                .iter()
+                .any(|entry| entry.key.eq_ignore_ascii_case("memory"))
+    }) {
+        "info"
+    } else {
+        "memory"
+    };
+    // Each `Speed` line belongs to one physical CPU, so core numbers repeat on multi-CPU hosts.
+    let mut cpu_index = 0usize;
+
+    for section in &report.sections {
+        let title = section.title.to_lowercase();
+        for entry in &section.entries {
+            let key = entry.key.to_lowercase();
+            let fields = labelled_fields(&entry.value)
+                .into_iter()
+                .map(|field| (field.label.map(|label| label.to_lowercase()), field.value))
+                .collect::<Vec<_>>();
+            let field = |label: &str| {
+                fields
+                    .iter()
+                    .find(|(name, _)| name.as_deref() == Some(label))
+                    .map(|(_, value)| value.as_str())
+            };
+
+            if title == "sensors" && key.contains("temperatures") {
+                let mut previous: Option<&str> = None;
+                for (label, value) in &fields {
+                    let Some(label) = label.as_deref() else {
+                        continue;
+                    };
+                    if let Some(celsius) = parse_celsius(value) {
+                        // `gpu: amdgpu temp: 44.0 C` names the sensor in the field before `temp`.
+                        let sensor = match (label, previous) {
+                            ("temp", Some(owner)) => owner,
+                            _ => label,
+                        };
+                        push(
+                            "inxi_temperature_celsius",
+                            vec![("sensor", sensor.to_string())],
+                            celsius,
+                        );
+                    }
+                    previous = Some(label);
+                }
+            }
+
+            if title == "sensors" && key.contains("fan speeds") {
+                for (label, value) in &fields {
+                    if let (Some(label), Some(rpm)) = (label, leading_number(value)) {
+                        push("inxi_fan_speed_rpm", vec![("fan", label.clone())], rpm);
+                    }
+                }
+            }
+
+            if title == memory_title && is_memory_entry(&title, &key) {
+                if let Some(total) = field("total").and_then(parse_bytes) {
+                    push("inxi_memory_total_bytes", Vec::new(), total);
+                }
+                if let Some(used) = field("used").and_then(parse_bytes) {
+                    push("inxi_memory_used_bytes", Vec::new(), used);
+                }
+            }
+
+            if title == "drives" && key == "local storage" {
+                if let Some(total) = field("total").and_then(parse_bytes) {
+                    push("inxi_disk_total_bytes", Vec::new(), total);
+                }
+                if let Some(used) = field("used") {
+                    if let Some(bytes) = parse_bytes(used) {
+                        push("inxi_disk_used_bytes", Vec::new(), bytes);
+                    }
+                    if let Some(percent) = parse_percent(used) {
+                        push("inxi_disk_used_percent", Vec::new(), percent);
+                    }
+                }
+            }
+
+            if title == "drives" && key.starts_with("id-") {
+                let device = leading_text(&fields)
+                    .unwrap_or(entry.key.as_str())
+                    .to_string();
+                if let Some(size) = field("size").and_then(parse_bytes) {
+                    push(
+                        "inxi_drive_size_bytes",
+                        vec![
+                            ("device", device.clone()),
+                            ("model", field("model").unwrap_or_default().to_string()),
+                        ],
+                        size,
+                    );
+                }
+                if let Some(celsius) = field("temp").and_then(parse_celsius) {
+                    push(
+                        "inxi_temperature_celsius",
+                        vec![("sensor", device)],
+                        celsius,
+                    );
+                }
+            }
+
+            if title == "battery"
+                && key.starts_with("id-")
+                && let Some(percent) = field("charge").and_then(parse_percent)
+            {
+                let battery = leading_text(&fields).unwrap_or(entry.key.as_str());
+                push(
+                    "inxi_battery_charge_percent",
+                    vec![("battery", battery.to_string())],
+                    percent,
+                );
+            }
+
+            if title == "cpu" && key.starts_with("speed") {
+                collect_cpu_speeds(&cpu_index.to_string(), &fields, &mut push);
+                cpu_index += 1;
+            }
+
+            if title == "cpu"
+                && key == "info"
+                && let Some(model) = field("model")
+            {
+                push("inxi_cpu_info", vec![("model", model.to_string())], 1.0);
+            }
+
+            if title == "system" {
+                if let Some(kernel) = entry_or_field(&key, "kernel", &fields) {
+                    push(
+                        "inxi_kernel_info",
+                        vec![("release", first_word(&kernel))],
+                        1.0,
+                    );
+                }
+                if let Some(distro) = entry_or_field(&key, "distro", &fields) {
+                    push("inxi_distro_info", vec![("name", distro)], 1.0);
+                }
+            }
+        }
+    }
+
+    samples
+}
+
+fn collect_cpu_speeds(
+    cpu: &str,
+    fields: &[(Option<String>, String)],
+    push: &mut impl FnMut(&'static str, Vec<(&'static str, String)>, f64),
+) {
+    for (label, value) in fields {
+        let Some(label) = label.as_deref() else {
+            continue;
+        };
+        if label == "avg" || label.chars().all(|ch| ch.is_ascii_digit()) {
+            if let Some(mhz) = leading_number(value) {
+                push(
+                    "inxi_cpu_speed_mhz",
+                    vec![("cpu", cpu.to_string()), ("core", label.to_string())],
+                    mhz,
+                );
+            }
+        } else if label == "min/max" {
+            let mut limits = value.split(['/', ' ']).filter_map(|part| part.parse().ok());
+            if let (Some(min), Some(max)) = (limits.next(), limits.next()) {
+                push(
+                    "inxi_cpu_speed_limit_mhz",
+                    vec![("cpu", cpu.to_string()), ("limit", "min".to_string())],
+                    min,
+                );
+                push(
+                    "inxi_cpu_speed_limit_mhz",
+                    vec![("cpu", cpu.to_string()), ("limit", "max".to_string())],
+                    max,
+                );
+            }
+        }
+    }
+}
+
+fn is_memory_entry(title: &str, key: &str) -> bool {
+    (title == "info" && key == "memory")
+        || (title == "memory" && (key.contains("ram") || key == "system ram"))
+}
+
+/// Value of `label` when it is the entry key itself or an inline field of the entry.
+fn entry_or_field(key: &str, label: &str, fields: &[(Option<String>, String)]) -> Option<String> {
+    fields
+        .iter()
+        .find(|(name, _)| match name {
+            Some(name) => name == label,
+            None => key == label,
+        })
+        .map(|(_, value)| value.clone())
+        .filter(|value| !value.is_empty())
+}
+
+fn leading_text(fields: &[(Option<String>, String)]) -> Option<&str> {
+    fields
+        .first()
+        .filter(|(label, value)| label.is_none() && !value.is_empty())
+        .map(|(_, value)| value.as_str())
+}
+
+fn first_word(value: &str) -> String {
+    value
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn leading_number(value: &str) -> Option<f64> {
+    value.split_whitespace().next()?.parse().ok()
+}
+
+fn parse_celsius(value: &str) -> Option<f64> {
+    let mut tokens = value.split_whitespace();
+    let number = tokens.next()?.parse().ok()?;
+    matches!(tokens.next(), Some("C")).then_some(number)
+}
+
+/// Parses `16 GiB` / `4.2 GiB (27.1%)` into bytes.
+pub(crate) fn parse_bytes(value: &str) -> Option<f64> {
+    let mut tokens = value.split_whitespace();
+    let number: f64 = tokens.next()?.parse().ok()?;
+    let multiplier = match tokens.next()? {
+        "B" => 1.0,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        "kB" | "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        _ => return None,
+    };
+    Some((number * multiplier).round())
+}
+
+/// Extracts the percentage from values such as `120.5 GiB (25.3%)` or `45.0 Wh (90.0%)`.
+fn parse_percent(value: &str) -> Option<f64> {
+    let start = value.find('(')?;
+    let end = value[start..].find("%)")? + start;
+    value[start + 1..end].trim().parse().ok()
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &[(&str, String)], value: f64) {
+    out.push_str(name);
+    if !labels.is_empty() {
+        let rendered = labels
+            .iter()
+            .map(|(key, value)| format!("{key}=\"{}\"", escape_label(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+        let _ = write!(out, "{{{rendered}}}");
+    }
+    let _ = writeln!(out, " {value}");
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModeMetrics, parse_bytes, render};
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    const SAMPLE: &str = "System:\n  Host: box Kernel: 6.12.68-1 arch: x86_64\n  Desktop: KDE Distro: Arch Linux\nCPU:\n  Info: quad core model: AMD Ryzen 5 3400G bits: 64\n  Speed (MHz): avg: 3400 min/max: 1400/4200 cores: 1: 3300 2: 3500\nDrives:\n  Local Storage: total: 476.94 GiB used: 120.5 GiB (25.3%)\nSensors:\n  System Temperatures: cpu: 45.0 C gpu: amdgpu temp: 44.0 C\n  Fan Speeds (rpm): cpu: 1200\nInfo:\n  Memory: total: 16 GiB used: 4 GiB (25%)\n";
+
+    #[test]
+    fn exports_hardware_gauges_and_internals() {
+        let report = parse_system_report(SAMPLE, InxiMode::Full).expect("report");
+        let mut metrics = ModeMetrics::default();
+        metrics.observe(0.3);
+        let text = render(Some(&report), &[(InxiMode::Full, metrics)]);
+
+        for line in [
+            "inxi_temperature_celsius{sensor=\"cpu\"} 45",
+            "inxi_temperature_celsius{sensor=\"gpu\"} 44",
+            "inxi_fan_speed_rpm{fan=\"cpu\"} 1200",
+            "inxi_memory_total_bytes 17179869184",
+            "inxi_disk_used_percent 25.3",
+            "inxi_cpu_speed_mhz{cpu=\"0\",core=\"2\"} 3500",
+            "inxi_cpu_speed_limit_mhz{cpu=\"0\",limit=\"max\"} 4200",
+            "inxi_kernel_info{release=\"6.12.68-1\"} 1",
+            "inxi_distro_info{name=\"Arch Linux\"} 1",
+            "inxi_cpu_info{model=\"AMD Ryzen 5 3400G\"} 1",
+            "inxi_dash_collection_duration_seconds_bucket{mode=\"full\",le=\"0.25\"} 0",
+            "inxi_dash_collection_duration_seconds_bucket{mode=\"full\",le=\"0.5\"} 1",
+            "inxi_dash_scrape_success 1",
+        ] {
+            assert!(text.contains(line), "missing `{line}` in:\n{text}");
+        }
+    }
+
+    #[test]
+    fn never_repeats_a_series() {
+        let report = parse_system_report(
+            "CPU:\n  Speed (MHz): avg: 3400 cores: 1: 3300 2: 3500\n  Speed (MHz): avg: 3000 cores: 1: 2900 2: 3100\nMemory:\n  System RAM: total: 32 GiB\n  RAM: total: 15.5 GiB used: 6 GiB (38.7%)\nSensors:\n  System Temperatures: cpu: 45.0 C cpu: 47.0 C\nInfo:\n  Memory: total: 16 GiB used: 4 GiB (25%)\n",
+            InxiMode::Full,
+        )
+        .expect("report");
+        let text = render(Some(&report), &[]);
+        let series = text
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| line.rsplit_once(' ').map_or(line, |(series, _)| series))
+            .collect::<Vec<_>>();
+        let mut unique = series.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(series.len(), unique.len(), "repeated series in:\n{text}");
+
+        assert!(text.contains("inxi_memory_total_bytes 17179869184\n"));
+        assert!(text.contains("inxi_temperature_celsius{sensor=\"cpu\"} 45\n"));
+        assert!(text.contains("inxi_cpu_speed_mhz{cpu=\"1\",core=\"2\"} 3100\n"));
+    }
+
+    #[test]
+    fn parses_binary_and_decimal_sizes() {
+        assert_eq!(parse_bytes("1 KiB"), Some(1024.0));
+        assert_eq!(parse_bytes("1.5 GB (10%)"), Some(1.5e9));
+        assert_eq!(parse_bytes("N/A"), None);
+    }
+}
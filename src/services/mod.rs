@@ -3,7 +3,21 @@
 // Extension guidance: Introduce new services here and provide a shared state if required.
 // Security considerations: Validate service inputs before invoking system commands or parsing user data.
 
+pub mod access;
+pub mod diff;
 pub mod inxi_service;
+pub mod lookup;
+pub mod metrics;
+pub mod openapi;
 pub mod parser;
+pub mod provenance;
+pub mod rate_limit;
+pub mod redaction;
+pub mod schema;
+pub mod search;
+pub mod snapshots;
 
+pub use access::{AccessPolicy, Role};
 pub use inxi_service::{InxiMode, InxiService};
+pub use rate_limit::RateLimiter;
+pub use snapshots::SnapshotStore;
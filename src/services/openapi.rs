@@ -0,0 +1,533 @@
+// Responsibility: Assemble the OpenAPI 3 document describing the /api/v1 surface.
+// Design reasoning: Component schemas are derived from the Rust models via schemars, so the contract tracks the types.
+// Extension guidance: When adding an endpoint, add its path item below and register any new response type as a component.
+// Security considerations: The document is static metadata; it lists the bearer scheme but never any configured tokens.
+
+use schemars::generate::SchemaSettings;
+use serde_json::{Value, json};
+
+use crate::config::{
+    API_ROUTE, API_V1_CARD_PNG_ROUTE, API_V1_CARD_SVG_ROUTE, API_V1_DIFF_ROUTE,
+    API_V1_ENTRIES_ROUTE, API_V1_HEALTH_ROUTE, API_V1_MODES_ROUTE, API_V1_OPENAPI_ROUTE,
+    API_V1_SCHEMA_ROUTE, API_V1_SEARCH_ROUTE, API_V1_SECTION_ROUTE, API_V1_SECTIONS_ROUTE,
+    API_V1_SESSION_ROUTE, API_V1_SNAPSHOT_ROUTE, API_V1_SNAPSHOTS_ROUTE, API_V1_STREAM_ROUTE,
+    API_V1_SYSTEM_ROUTE, DEFAULT_MODE, HEALTHZ_ROUTE, READYZ_ROUTE, SESSION_COOKIE,
+    SNAPSHOT_PAGE_DEFAULT, SNAPSHOT_PAGE_MAX,
+};
+use crate::error::ErrorResponse;
+use crate::models::{
+    DiffRequest, EntryMatch, HealthStatus, LoginRequest, ModeInfo, ReadinessReport, ReportDiff,
+    SearchResults, SectionSummary, SessionInfo, Snapshot, SnapshotCreate, SnapshotPage,
+    SnapshotSummary, SnapshotUpdate, SystemReport,
+};
+use crate::rendering::ReportFormat;
+use crate::rendering::theme::PALETTES;
+use crate::services::InxiMode;
+
+pub fn document() -> Value {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    // Each subschema_for call registers the type (and anything it references) under components/schemas.
+    generator.subschema_for::<SystemReport>();
+    generator.subschema_for::<SectionSummary>();
+    generator.subschema_for::<EntryMatch>();
+    generator.subschema_for::<SearchResults>();
+    generator.subschema_for::<ModeInfo>();
+    generator.subschema_for::<HealthStatus>();
+    generator.subschema_for::<ReadinessReport>();
+    generator.subschema_for::<Snapshot>();
+    generator.subschema_for::<SnapshotPage>();
+    generator.subschema_for::<SnapshotSummary>();
+    generator.subschema_for::<SnapshotCreate>();
+    generator.subschema_for::<SnapshotUpdate>();
+    generator.subschema_for::<DiffRequest>();
+    generator.subschema_for::<ReportDiff>();
+    generator.subschema_for::<SessionInfo>();
+    generator.subschema_for::<LoginRequest>();
+    generator.subschema_for::<ErrorResponse>();
+    let components = generator.take_definitions(true);
+
+    let mut system = report_operation(
+        "getSystemReport",
+        "Collect a full system report",
+        reference("#/components/schemas/SystemReport"),
+        vec![json!({
+            "name": "format",
+            "in": "query",
+            "required": false,
+            "description": "Response format; overrides the Accept header",
+            "schema": {
+                "type": "string",
+                "enum": ReportFormat::ALL.iter().map(|format| format.as_str()).collect::<Vec<_>>(),
+                "default": "json"
+            }
+        })],
+    );
+    for format in ReportFormat::ALL {
+        let media_type = format.content_type().split(';').next().unwrap_or_default();
+        if format != ReportFormat::Json {
+            system["responses"]["200"]["content"][media_type] =
+                json!({ "schema": { "type": "string" } });
+        }
+    }
+    system["responses"]["406"] = error_response("No supported format in the Accept header");
+    let mut legacy = system.clone();
+    legacy["deprecated"] = json!(true);
+    legacy["operationId"] = json!("getSystemReportLegacy");
+    legacy["description"] = json!(format!("Unversioned alias of {API_V1_SYSTEM_ROUTE}."));
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "inxi-dash API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Structured Linux system information collected with inxi."
+        },
+        "servers": [{ "url": "/" }],
+        "paths": {
+            API_V1_SYSTEM_ROUTE: { "get": system },
+            API_ROUTE: { "get": legacy },
+            API_V1_SECTIONS_ROUTE: {
+                "get": report_operation(
+                    "listSections",
+                    "List report section titles with entry counts",
+                    array_of("#/components/schemas/SectionSummary"),
+                    Vec::new(),
+                )
+            },
+            API_V1_SECTION_ROUTE: {
+                "get": with_not_found(report_operation(
+                    "getSections",
+                    "All sections with this title (case-insensitive); inxi may repeat titles",
+                    array_of("#/components/schemas/SystemSection"),
+                    vec![json!({
+                        "name": "title",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string" }
+                    })],
+                ), "No section with this title")
+            },
+            API_V1_ENTRIES_ROUTE: {
+                "get": with_not_found(report_operation(
+                    "findEntries",
+                    "Entries or inline fields whose key matches (case-insensitive)",
+                    array_of("#/components/schemas/EntryMatch"),
+                    vec![
+                        json!({
+                            "name": "key",
+                            "in": "query",
+                            "required": true,
+                            "schema": { "type": "string" }
+                        }),
+                        json!({
+                            "name": "section",
+                            "in": "query",
+                            "required": false,
+                            "description": "Restrict matches to sections with this title",
+                            "schema": { "type": "string" }
+                        }),
+                    ],
+                ), "No entry with this key")
+            },
+            API_V1_SEARCH_ROUTE: {
+                "get": report_operation(
+                    "searchEntries",
+                    "Entries matching free text, key and/or section, with highlight offsets",
+                    reference("#/components/schemas/SearchResults"),
+                    vec![
+                        query_parameter("q", "Case-insensitive text matched in keys and values"),
+                        query_parameter("key", "Entry key or inline label, matched exactly ignoring case"),
+                        query_parameter("section", "Only entries of sections with this title"),
+                        json!({
+                            "name": "regex",
+                            "in": "query",
+                            "required": false,
+                            "description": "Interpret q and key as case-insensitive regular expressions",
+                            "schema": { "type": "boolean", "default": false }
+                        }),
+                    ],
+                )
+            },
+            API_V1_STREAM_ROUTE: { "get": stream_operation() },
+            API_V1_CARD_SVG_ROUTE: { "get": card_operation("getSpecCardSvg", "image/svg+xml") },
+            API_V1_CARD_PNG_ROUTE: { "get": card_operation("getSpecCardPng", "image/png") },
+            API_V1_SNAPSHOTS_ROUTE: {
+                "get": {
+                    "operationId": "listSnapshots",
+                    "summary": "Stored snapshots, newest first",
+                    "parameters": [
+                        json!({
+                            "name": "offset",
+                            "in": "query",
+                            "required": false,
+                            "schema": { "type": "integer", "minimum": 0, "default": 0 }
+                        }),
+                        json!({
+                            "name": "limit",
+                            "in": "query",
+                            "required": false,
+                            "schema": { "type": "integer", "minimum": 1, "maximum": SNAPSHOT_PAGE_MAX, "default": SNAPSHOT_PAGE_DEFAULT }
+                        }),
+                        query_parameter("label", "Only snapshots whose label contains this text (case-insensitive)"),
+                    ],
+                    "responses": {
+                        "200": json_response("One page of snapshots", reference("#/components/schemas/SnapshotPage")),
+                        "400": error_response("Invalid limit"),
+                        "403": error_response("Unrecognized access token"),
+                        "503": error_response("Snapshot storage unavailable")
+                    }
+                },
+                "post": {
+                    "operationId": "createSnapshot",
+                    "summary": "Save a held report (`report`) or a fresh collection of `mode` as a snapshot",
+                    "requestBody": json_body("#/components/schemas/SnapshotCreate"),
+                    "responses": {
+                        "201": json_response("Saved snapshot", reference("#/components/schemas/SnapshotSummary")),
+                        "400": error_response("Invalid body, mode or annotation"),
+                        "403": error_response("Role may not manage snapshots or request this mode"),
+                        "404": error_response("Report ID unknown or expired"),
+                        "429": error_response("Rate limit or inxi concurrency cap exceeded; see Retry-After"),
+                        "502": error_response("inxi execution failed"),
+                        "503": error_response("Snapshot storage unavailable")
+                    }
+                }
+            },
+            API_V1_SNAPSHOT_ROUTE: {
+                "get": {
+                    "operationId": "getSnapshot",
+                    "summary": "A stored snapshot with its report, restricted for the caller's role",
+                    "parameters": [
+                        snapshot_id_parameter(),
+                        json!({
+                            "name": "redact",
+                            "in": "query",
+                            "required": false,
+                            "description": "Mask identifying fields. Only admins may pass false.",
+                            "schema": { "type": "boolean" }
+                        }),
+                    ],
+                    "responses": {
+                        "200": json_response("Stored snapshot", reference("#/components/schemas/Snapshot")),
+                        "400": error_response("Snapshot IDs are UUIDs"),
+                        "403": error_response("Mode or unredacted output not permitted for the caller's role"),
+                        "404": error_response("No snapshot with this ID"),
+                        "503": error_response("Snapshot storage unavailable")
+                    }
+                },
+                "patch": {
+                    "operationId": "updateSnapshot",
+                    "summary": "Replace the label and/or notes; empty strings clear them",
+                    "parameters": [snapshot_id_parameter()],
+                    "requestBody": json_body("#/components/schemas/SnapshotUpdate"),
+                    "responses": {
+                        "200": json_response("Updated snapshot", reference("#/components/schemas/SnapshotSummary")),
+                        "400": error_response("Invalid body or annotation"),
+                        "403": error_response("Role may not manage snapshots"),
+                        "404": error_response("No snapshot with this ID"),
+                        "503": error_response("Snapshot storage unavailable")
+                    }
+                },
+                "delete": {
+                    "operationId": "deleteSnapshot",
+                    "summary": "Delete a stored snapshot",
+                    "parameters": [snapshot_id_parameter()],
+                    "responses": {
+                        "204": { "description": "Snapshot deleted" },
+                        "403": error_response("Role may not manage snapshots"),
+                        "404": error_response("No snapshot with this ID"),
+                        "503": error_response("Snapshot storage unavailable")
+                    }
+                }
+            },
+            API_V1_DIFF_ROUTE: {
+                "post": {
+                    "operationId": "diffReports",
+                    "summary": "What changed between two reports, or between an uploaded report and the live one",
+                    "parameters": [json!({
+                        "name": "format",
+                        "in": "query",
+                        "required": false,
+                        "schema": { "type": "string", "enum": ["json", "md"], "default": "json" }
+                    })],
+                    "requestBody": json_body("#/components/schemas/DiffRequest"),
+                    "responses": {
+                        "200": {
+                            "description": "Changes grouped by section",
+                            "content": {
+                                "application/json": { "schema": reference("#/components/schemas/ReportDiff") },
+                                "text/markdown": { "schema": { "type": "string" } }
+                            }
+                        },
+                        "400": error_response("Invalid body, ignore rule or format"),
+                        "403": error_response("Live mode or unredacted output not permitted for the caller's role"),
+                        "429": error_response("Rate limit or inxi concurrency cap exceeded; see Retry-After"),
+                        "502": error_response("inxi execution failed")
+                    }
+                }
+            },
+            API_V1_SESSION_ROUTE: {
+                "get": {
+                    "operationId": "getSession",
+                    "summary": "The caller's role and how it was identified",
+                    "responses": {
+                        "200": json_response("Caller's role", reference("#/components/schemas/SessionInfo")),
+                        "403": error_response("Unrecognized access token")
+                    }
+                },
+                "post": {
+                    "operationId": "createSession",
+                    "summary": "Exchange an access token for an HttpOnly session cookie, for browsers",
+                    "requestBody": json_body("#/components/schemas/LoginRequest"),
+                    "responses": {
+                        "200": json_response("Signed in; the response sets the session cookie", reference("#/components/schemas/SessionInfo")),
+                        "400": error_response("Invalid body"),
+                        "403": error_response("Unrecognized access token"),
+                        "429": error_response("Too many attempts; see Retry-After")
+                    }
+                },
+                "delete": {
+                    "operationId": "deleteSession",
+                    "summary": "Sign out and clear the session cookie",
+                    "security": [],
+                    "responses": {
+                        "204": { "description": "Signed out" }
+                    }
+                }
+            },
+            API_V1_MODES_ROUTE: {
+                "get": {
+                    "operationId": "listModes",
+                    "summary": "List inxi detail levels and whether the caller may use them",
+                    "responses": {
+                        "200": json_response("Available modes", array_of("#/components/schemas/ModeInfo")),
+                        "403": error_response("Unrecognized access token")
+                    }
+                }
+            },
+            API_V1_HEALTH_ROUTE: {
+                "get": {
+                    "operationId": "getHealth",
+                    "summary": "Report API liveness without running inxi",
+                    "security": [],
+                    "responses": {
+                        "200": json_response("Service is alive", reference("#/components/schemas/HealthStatus"))
+                    }
+                }
+            },
+            HEALTHZ_ROUTE: {
+                "get": {
+                    "operationId": "getLiveness",
+                    "summary": "Process liveness probe; alias of the v1 health endpoint",
+                    "security": [],
+                    "responses": {
+                        "200": json_response("Process is alive", reference("#/components/schemas/HealthStatus"))
+                    }
+                }
+            },
+            READYZ_ROUTE: {
+                "get": {
+                    "operationId": "getReadiness",
+                    "summary": "Readiness probe: inxi availability, last collection outcomes and cache state",
+                    "security": [],
+                    "responses": {
+                        "200": json_response("Ready to collect reports", reference("#/components/schemas/ReadinessReport")),
+                        "503": json_response("inxi is not available", reference("#/components/schemas/ReadinessReport"))
+                    }
+                }
+            },
+            API_V1_SCHEMA_ROUTE: {
+                "get": {
+                    "operationId": "getReportSchema",
+                    "summary": "JSON Schema (draft 2020-12) of SystemReport for the current schema_version",
+                    "security": [],
+                    "responses": {
+                        "200": {
+                            "description": "JSON Schema document",
+                            "content": { "application/schema+json": { "schema": { "type": "object" } } }
+                        }
+                    }
+                }
+            },
+            API_V1_OPENAPI_ROUTE: {
+                "get": {
+                    "operationId": "getOpenApi",
+                    "summary": "This OpenAPI document",
+                    "security": [],
+                    "responses": {
+                        "200": json_response("OpenAPI 3 document", json!({ "type": "object" }))
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": components,
+            "securitySchemes": {
+                "bearer": { "type": "http", "scheme": "bearer" },
+                "session": { "type": "apiKey", "in": "cookie", "name": SESSION_COOKIE }
+            }
+        },
+        "security": [{ "bearer": [] }, { "session": [] }, {}]
+    })
+}
+
+fn report_operation(id: &str, summary: &str, body: Value, extra: Vec<Value>) -> Value {
+    let mut parameters = extra;
+    parameters.extend([
+        json!({
+            "name": "mode",
+            "in": "query",
+            "required": false,
+            "schema": {
+                "type": "string",
+                "enum": InxiMode::ALL.iter().map(|mode| mode.as_str()).collect::<Vec<_>>(),
+                "default": DEFAULT_MODE
+            }
+        }),
+        json!({
+            "name": "redact",
+            "in": "query",
+            "required": false,
+            "description": "Mask identifying fields. Only admins may pass false.",
+            "schema": { "type": "boolean" }
+        }),
+    ]);
+
+    json!({
+        "operationId": id,
+        "summary": summary,
+        "parameters": parameters,
+        "responses": {
+            "200": json_response("Collected report", body),
+            "400": error_response("Unknown mode"),
+            "403": error_response("Mode or unredacted output not permitted for the caller's role"),
+            "429": error_response("Rate limit or inxi concurrency cap exceeded; see Retry-After"),
+            "502": error_response("inxi execution failed")
+        }
+    })
+}
+
+fn stream_operation() -> Value {
+    let mut operation = report_operation(
+        "streamSystemReport",
+        "Server-Sent Events: a `report` event, then `patch` events (RFC 6902) on a server schedule",
+        reference("#/components/schemas/SystemReport"),
+        vec![json!({
+            "name": "delta",
+            "in": "query",
+            "required": false,
+            "description": "Send JSON-patch deltas after the first report; false sends full reports every tick",
+            "schema": { "type": "boolean", "default": true }
+        })],
+    );
+    operation["responses"]["200"] = json!({
+        "description": "Event stream; `report` data is a SystemReport, `patch` data `{base, operations}` where `base` is the ID of the report the operations apply to, `error` data an ErrorResponse",
+        "content": { "text/event-stream": { "schema": { "type": "string" } } }
+    });
+    operation
+}
+
+fn card_operation(id: &str, media_type: &str) -> Value {
+    let mut operation = report_operation(
+        id,
+        "Shareable spec card image summarising the report",
+        json!({ "type": "string" }),
+        vec![json!({
+            "name": "theme",
+            "in": "query",
+            "required": false,
+            "description": "Dashboard theme palette for the card colours",
+            "schema": {
+                "type": "string",
+                "enum": PALETTES.iter().map(|palette| palette.name).collect::<Vec<_>>(),
+                "default": "default"
+            }
+        })],
+    );
+    operation["responses"]["200"] = json!({
+        "description": "Spec card image",
+        "content": { media_type: { "schema": { "type": "string", "format": "binary" } } }
+    });
+    operation["responses"]["400"] = error_response("Unknown mode or theme");
+    operation
+}
+
+fn query_parameter(name: &str, description: &str) -> Value {
+    json!({
+        "name": name,
+        "in": "query",
+        "required": false,
+        "description": description,
+        "schema": { "type": "string" }
+    })
+}
+
+fn snapshot_id_parameter() -> Value {
+    json!({
+        "name": "id",
+        "in": "path",
+        "required": true,
+        "schema": { "type": "string", "format": "uuid" }
+    })
+}
+
+fn json_body(schema: &str) -> Value {
+    json!({
+        "required": true,
+        "content": { "application/json": { "schema": reference(schema) } }
+    })
+}
+
+fn with_not_found(mut operation: Value, description: &str) -> Value {
+    operation["responses"]["404"] = error_response(description);
+    operation
+}
+
+fn reference(schema: &str) -> Value {
+    json!({ "$ref": schema })
+}
+
+fn array_of(schema: &str) -> Value {
+    json!({ "type": "array", "items": reference(schema) })
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema } }
+    })
+}
+
+fn error_response(description: &str) -> Value {
+    json_response(description, reference("#/components/schemas/ErrorResponse"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::document;
+
+    #[test]
+    fn references_resolve_to_registered_components() {
+        let doc = document();
+        let schemas = doc["components"]["schemas"]
+            .as_object()
+            .expect("components");
+        for name in [
+            "SystemReport",
+            "SystemSection",
+            "SystemEntry",
+            "ModeInfo",
+            "SnapshotPage",
+            "SnapshotSummary",
+            "ErrorResponse",
+        ] {
+            assert!(schemas.contains_key(name), "missing component {name}");
+        }
+
+        let text = doc.to_string();
+        for reference in text.split("\"$ref\":\"").skip(1) {
+            let target = reference.split('"').next().unwrap_or_default();
+            let name = target.trim_start_matches("#/components/schemas/");
+            assert!(schemas.contains_key(name), "dangling reference {target}");
+        }
+    }
+}
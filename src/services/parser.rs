@@ -4,10 +4,11 @@
 // Security considerations: All extracted strings are sanitized before leaving this module, preventing ANSI escape leakage.
 
 use crate::error::AppError;
-use crate::models::{SystemEntry, SystemReport, SystemSection};
-use crate::services::InxiMode;
+use crate::models::{ReportMetadata, SCHEMA_VERSION, SystemEntry, SystemReport, SystemSection};
+use crate::services::{InxiMode, provenance};
 use std::mem;
 use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
 
 pub fn parse_system_report(raw: &str, mode: InxiMode) -> Result<SystemReport, AppError> {
     let timestamp = SystemTime::now()
@@ -17,11 +18,16 @@ pub fn parse_system_report(raw: &str, mode: InxiMode) -> Result<SystemReport, Ap
 
     let sections = parse_sections(raw);
 
-    Ok(SystemReport {
+    let mut report = SystemReport {
+        schema_version: SCHEMA_VERSION.to_string(),
+        id: Uuid::new_v4().to_string(),
         timestamp,
         mode: mode.as_str().to_string(),
+        metadata: ReportMetadata::default(),
         sections,
-    })
+    };
+    report.metadata = provenance::describe(&report);
+    Ok(report)
 }
 
 fn parse_sections(raw: &str) -> Vec<SystemSection> {
@@ -45,12 +51,12 @@ fn parse_sections(raw: &str) -> Vec<SystemSection> {
             continue;
         }
 
-        if is_continuation_line(raw_line, line) {
-            if let Some(last) = current_entries.last_mut() {
-                last.value.push(' ');
-                last.value.push_str(line);
-                continue;
-            }
+        if is_continuation_line(raw_line, line)
+            && let Some(last) = current_entries.last_mut()
+        {
+            last.value.push(' ');
+            last.value.push_str(line);
+            continue;
         }
 
         if let Some(entry) = parse_entry(line) {
@@ -93,7 +99,7 @@ fn parse_section_title(line: &str) -> Option<String> {
     }
 
     // Check if it starts with an uppercase letter which is common for sections
-    if !title.chars().next().map_or(false, |c| c.is_uppercase()) {
+    if !title.chars().next().is_some_and(|c| c.is_uppercase()) {
         return None;
     }
 
@@ -118,8 +124,10 @@ fn is_continuation_line(raw_line: &str, trimmed: &str) -> bool {
         return false;
     };
 
+    // Wrapped core lists appear as `4 3400` in older inxi and `4: 3400` in newer releases.
+    let numbered = first_token.trim_end_matches(':');
     first_token.starts_with('(')
-        || first_token.chars().all(|ch| ch.is_ascii_digit())
+        || (!numbered.is_empty() && numbered.chars().all(|ch| ch.is_ascii_digit()))
         || first_token.contains('=')
 }
 
@@ -193,4 +201,15 @@ mod tests {
             entry.value
         );
     }
+
+    #[test]
+    fn appends_wrapped_labelled_core_lists() {
+        let sample = "CPU:\n  Speed (MHz): avg: 3400 cores: 1: 3400 2: 3400\n    3: 3400 4: 3400\n";
+
+        let report = parse_system_report(sample, InxiMode::Basic).expect("report should parse");
+        let entries = &report.sections[0].entries;
+
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].value.ends_with("3: 3400 4: 3400"));
+    }
 }
@@ -0,0 +1,96 @@
+// Responsibility: Describe where and how a report was collected: host, OS, tool versions, machine ID hash and times.
+// Design reasoning: Fields derivable from inxi output come from the report itself; only the machine ID needs the filesystem.
+// Extension guidance: Add fields to ReportMetadata, fill them here, and list them in `lookup::provenance` for exports.
+// Security considerations: The machine ID is only stored as an application-keyed hash, per machine-id(5), and is masked by redaction.
+
+use std::fs;
+
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use sha2::{Digest, Sha256};
+
+use crate::config::MACHINE_ID_PATHS;
+use crate::models::{ReportMetadata, SystemReport};
+use crate::services::lookup;
+
+/// Key mixed into the machine ID hash so it cannot be correlated with other applications' IDs.
+const MACHINE_ID_KEY: &[u8] = b"inxi-dash report metadata";
+
+/// Metadata derivable from the report content and its timestamp.
+pub fn describe(report: &SystemReport) -> ReportMetadata {
+    let inventory = lookup::inventory(report);
+    let collected = DateTime::<Utc>::from_timestamp(report.timestamp as i64, 0).unwrap_or_default();
+    ReportMetadata {
+        hostname: inventory.hostname,
+        machine_id_hash: None,
+        kernel: inventory.kernel,
+        distro: inventory.distro,
+        inxi_version: lookup::entries_keyed(report, "inxi", Some("Info"))
+            .into_iter()
+            .map(|found| found.value)
+            .find(|value| !value.is_empty()),
+        inxi_dash_version: env!("CARGO_PKG_VERSION").to_string(),
+        collection_ms: None,
+        collected_utc: collected.to_rfc3339_opts(SecondsFormat::Secs, true),
+        collected_local: collected
+            .with_timezone(&Local)
+            .to_rfc3339_opts(SecondsFormat::Secs, false),
+    }
+}
+
+/// Version number from an `inxi --version` line such as `inxi 3.3.34-00 (2024-03-18)`.
+pub fn inxi_version_number(line: &str) -> Option<String> {
+    let mut words = line.split_whitespace();
+    let first = words.next()?;
+    let version = if first.eq_ignore_ascii_case("inxi") {
+        words.next()?
+    } else {
+        first
+    };
+    Some(version.to_string())
+}
+
+/// Keyed hash of the first readable machine ID, or `None` on systems without one.
+pub fn machine_id_hash() -> Option<String> {
+    let id = MACHINE_ID_PATHS
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|id| id.trim().to_string())
+        .find(|id| !id.is_empty())?;
+    Some(keyed_hash(&id))
+}
+
+fn keyed_hash(id: &str) -> String {
+    let digest = Sha256::new()
+        .chain_update(MACHINE_ID_KEY)
+        .chain_update(id.as_bytes())
+        .finalize();
+    digest[..8]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, inxi_version_number, keyed_hash};
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    #[test]
+    fn derives_metadata_from_report_and_version_line() {
+        let raw = "System:\n  Host: box Kernel: 6.12.68 arch: x86_64\n  Desktop: KDE Distro: Arch Linux\nInfo:\n  Processes: 312 Shell: Zsh inxi: 3.3.34\n";
+        let mut report = parse_system_report(raw, InxiMode::Basic).expect("report");
+        report.timestamp = 1_792_349_580;
+        let metadata = describe(&report);
+
+        assert_eq!(metadata.hostname.as_deref(), Some("box"));
+        assert_eq!(metadata.kernel.as_deref(), Some("6.12.68"));
+        assert_eq!(metadata.distro.as_deref(), Some("Arch Linux"));
+        assert_eq!(metadata.inxi_version.as_deref(), Some("3.3.34"));
+        assert_eq!(metadata.collected_utc, "2026-10-18T18:53:00Z");
+        assert_eq!(
+            inxi_version_number("inxi 3.3.34-00 (2024-03-18)").as_deref(),
+            Some("3.3.34-00")
+        );
+        assert_eq!(keyed_hash("0123456789abcdef").len(), 16);
+    }
+}
@@ -0,0 +1,105 @@
+// Responsibility: Throttle how often a single client may trigger inxi collections using per-client token buckets.
+// Design reasoning: An in-memory bucket map is enough for a single-node service and avoids an external limiter dependency.
+// Extension guidance: Tune capacity and refill in config.rs, or key buckets by role to give operators higher budgets.
+// Security considerations: Keys are kept only in memory and stale buckets are pruned so hostile clients cannot grow the map forever.
+
+use crate::error::AppError;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of tracked clients after which idle, fully refilled buckets are discarded.
+const PRUNE_THRESHOLD: usize = 1024;
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_interval: Duration,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    /// Allows `capacity` immediate requests per client, regaining one every `refill_interval`.
+    pub fn new(capacity: u32, refill_interval: Duration) -> Self {
+        Self {
+            capacity: f64::from(capacity.max(1)),
+            refill_interval,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn check(&self, client: &str) -> Result<(), AppError> {
+        self.check_at(client, Instant::now())
+    }
+
+    fn check_at(&self, client: &str, now: Instant) -> Result<(), AppError> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if buckets.len() >= PRUNE_THRESHOLD {
+            self.prune(&mut buckets, now);
+        }
+
+        let bucket = buckets.entry(client.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            updated: now,
+        });
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        let refill = elapsed / self.refill_interval.as_secs_f64();
+        bucket.tokens = (bucket.tokens + refill).min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+
+        let missing = 1.0 - bucket.tokens;
+        let retry_after = (missing * self.refill_interval.as_secs_f64()).ceil() as u64;
+        tracing::warn!(retry_after, "client exceeded inxi request budget");
+        Err(AppError::RateLimited {
+            retry_after: retry_after.max(1),
+        })
+    }
+
+    fn prune(&self, buckets: &mut HashMap<String, Bucket>, now: Instant) {
+        let full_after = self.refill_interval.mul_f64(self.capacity);
+        buckets.retain(|_, bucket| now.saturating_duration_since(bucket.updated) < full_after);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimiter;
+    use crate::error::AppError;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn rejects_after_burst_and_recovers_after_refill() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert!(limiter.check_at("10.0.0.1", start).is_ok());
+        assert!(limiter.check_at("10.0.0.1", start).is_ok());
+        match limiter.check_at("10.0.0.1", start) {
+            Err(AppError::RateLimited { retry_after }) => assert_eq!(retry_after, 10),
+            other => panic!("expected rate limit, got {other:?}"),
+        }
+
+        assert!(limiter.check_at("10.0.0.2", start).is_ok());
+        assert!(
+            limiter
+                .check_at("10.0.0.1", start + Duration::from_secs(10))
+                .is_ok()
+        );
+    }
+}
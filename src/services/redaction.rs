@@ -0,0 +1,175 @@
+// Responsibility: Mask identifying values (serials, UUIDs, addresses) inside parsed report entries.
+// Design reasoning: inxi embeds `label: value` pairs inside entry values, so masking works on labels rather than positions.
+// Extension guidance: Extend SENSITIVE_LABELS (or STRICT_LABELS for the stricter export profile) when inxi adds identifying fields.
+// Security considerations: Redaction replaces values wholesale; partial masking would still leak vendor-specific serial prefixes.
+
+use crate::error::AppError;
+use crate::models::SystemReport;
+
+/// Placeholder inserted in place of masked values, matching inxi's own `-z` filter output.
+pub const REDACTED: &str = "<filter>";
+
+/// Labels (lowercase, without the trailing colon) whose values identify a specific machine or network.
+const SENSITIVE_LABELS: &[&str] = &[
+    "serial",
+    "uuid",
+    "mac",
+    "ip",
+    "ip-v4",
+    "ip-v6",
+    "wan-ip",
+    "host",
+    "hostname",
+    "machine-id",
+    "ssid",
+    "bssid",
+];
+
+/// Extra labels masked by the `strict` profile: names and paths that describe the owner's setup rather than hardware.
+const STRICT_LABELS: &[&str] = &[
+    "label", "mapped", "path", "wwn", "gateway", "dns", "domain", "user", "users",
+];
+
+/// How much of a report an export masks; `Standard` is what `redact=true` applies everywhere else.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RedactionProfile {
+    #[default]
+    None,
+    Standard,
+    Strict,
+}
+
+impl RedactionProfile {
+    pub const ALL: [RedactionProfile; 3] = [
+        RedactionProfile::None,
+        RedactionProfile::Standard,
+        RedactionProfile::Strict,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RedactionProfile::None => "none",
+            RedactionProfile::Standard => "standard",
+            RedactionProfile::Strict => "strict",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            RedactionProfile::None => "none",
+            RedactionProfile::Standard => "standard (serials, UUIDs, addresses, host names)",
+            RedactionProfile::Strict => "strict (standard plus labels, paths, network names)",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, AppError> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(RedactionProfile::None),
+            "standard" => Ok(RedactionProfile::Standard),
+            "strict" => Ok(RedactionProfile::Strict),
+            other => Err(AppError::InvalidQuery(format!(
+                "unknown redaction profile '{other}'; expected one of {}",
+                RedactionProfile::ALL
+                    .map(RedactionProfile::as_str)
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// The profile equivalent to a plain `redact=` flag.
+    pub fn from_redact(redact: bool) -> Self {
+        if redact {
+            RedactionProfile::Standard
+        } else {
+            RedactionProfile::None
+        }
+    }
+
+    pub fn masks(self) -> bool {
+        self != RedactionProfile::None
+    }
+
+    pub fn apply(self, report: &mut SystemReport) {
+        let strict = match self {
+            RedactionProfile::None => return,
+            RedactionProfile::Standard => false,
+            RedactionProfile::Strict => true,
+        };
+        let metadata = &mut report.metadata;
+        for identifier in [&mut metadata.hostname, &mut metadata.machine_id_hash] {
+            if identifier.is_some() {
+                *identifier = Some(REDACTED.to_string());
+            }
+        }
+        for section in &mut report.sections {
+            for entry in &mut section.entries {
+                entry.value = redact_value(&entry.value, is_sensitive(&entry.key, strict), strict);
+            }
+        }
+    }
+}
+
+pub fn redact_report(report: &mut SystemReport) {
+    RedactionProfile::Standard.apply(report);
+}
+
+fn is_sensitive(label: &str, strict: bool) -> bool {
+    let normalized = label.trim().trim_end_matches(':').to_lowercase();
+    SENSITIVE_LABELS.contains(&normalized.as_str())
+        || (strict && STRICT_LABELS.contains(&normalized.as_str()))
+}
+
+fn redact_value(value: &str, leading_sensitive: bool, strict: bool) -> String {
+    let mut output = Vec::new();
+    let mut masking = leading_sensitive;
+    let mut masked = false;
+
+    for token in value.split_whitespace() {
+        if token.len() > 1 && token.ends_with(':') {
+            masking = is_sensitive(token, strict);
+            masked = false;
+            output.push(token);
+            continue;
+        }
+
+        if masking {
+            if !masked {
+                output.push(REDACTED);
+                masked = true;
+            }
+            continue;
+        }
+
+        output.push(token);
+    }
+
+    output.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::redact_value;
+
+    #[test]
+    fn masks_labelled_values_only() {
+        let value = "Micro-Star model: MS-7C02 v: 1.0 serial: ABC123 XYZ UEFI: AMI";
+        assert_eq!(
+            redact_value(value, false, false),
+            "Micro-Star model: MS-7C02 v: 1.0 serial: <filter> UEFI: AMI"
+        );
+        let partition = "ID-1: / size: 98 GiB fs: ext4 dev: /dev/nvme0n1p2 label: home-of-alice";
+        assert_eq!(
+            redact_value(partition, false, true),
+            "ID-1: / size: 98 GiB fs: ext4 dev: /dev/nvme0n1p2 label: <filter>"
+        );
+    }
+
+    #[test]
+    fn masks_leading_value_when_entry_key_is_sensitive() {
+        let value = "workstation Kernel: 6.12.68 arch: x86_64";
+        assert_eq!(
+            redact_value(value, true, false),
+            "<filter> Kernel: 6.12.68 arch: x86_64"
+        );
+    }
+}
@@ -0,0 +1,38 @@
+// Responsibility: Produce the JSON Schema describing SystemReport for API consumers and exports.
+// Design reasoning: Deriving the schema from the models with schemars keeps it in lockstep with what serde emits.
+// Extension guidance: Bump models::SCHEMA_VERSION whenever a change here would alter the generated schema.
+// Security considerations: The schema is static type metadata and contains no report values.
+
+use schemars::generate::SchemaSettings;
+use serde_json::{Value, json};
+
+use crate::config::API_V1_SCHEMA_ROUTE;
+use crate::models::{SCHEMA_VERSION, SystemReport};
+
+pub fn system_report_schema() -> Value {
+    let generator = SchemaSettings::draft2020_12().into_generator();
+    let mut schema = generator.into_root_schema_for::<SystemReport>().to_value();
+    if let Some(object) = schema.as_object_mut() {
+        object.insert(
+            "$id".to_string(),
+            json!(format!("{API_V1_SCHEMA_ROUTE}?version={SCHEMA_VERSION}")),
+        );
+        object.insert("version".to_string(), json!(SCHEMA_VERSION));
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::system_report_schema;
+    use crate::models::SCHEMA_VERSION;
+
+    #[test]
+    fn schema_requires_version_marker() {
+        let schema = system_report_schema();
+        assert_eq!(schema["version"], SCHEMA_VERSION);
+        let required = schema["required"].as_array().expect("required list");
+        assert!(required.iter().any(|field| field == "schema_version"));
+        assert!(schema["$defs"]["SystemSection"].is_object());
+    }
+}
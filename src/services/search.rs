@@ -0,0 +1,223 @@
+// Responsibility: Search report entries by free text, key and section, returning hits with highlight offsets.
+// Design reasoning: Free text is compiled to an escaped, case-insensitive regex so plain and regex searches share one matcher.
+// Extension guidance: Add new filters to SearchFilter and apply them in `search`; keep offsets in UTF-16 units for the browser.
+// Security considerations: Regex mode is opt-in, length- and size-limited, and the regex crate guarantees linear-time matching.
+
+use regex::{Regex, RegexBuilder};
+
+use crate::config::{SEARCH_MAX_PATTERN_LEN, SEARCH_MAX_RESULTS, SEARCH_REGEX_SIZE_LIMIT};
+use crate::error::AppError;
+use crate::models::{Highlight, HighlightField, SearchHit, SearchResults, SystemReport};
+
+/// Compiled search criteria; every present filter must match for an entry to be returned.
+pub struct SearchFilter {
+    text: Option<Regex>,
+    key: Option<KeyMatcher>,
+    section: Option<String>,
+}
+
+enum KeyMatcher {
+    Exact(String),
+    Pattern(Regex),
+}
+
+impl KeyMatcher {
+    fn matches(&self, candidate: &str) -> bool {
+        match self {
+            KeyMatcher::Exact(key) => candidate.to_lowercase() == *key,
+            KeyMatcher::Pattern(pattern) => pattern.is_match(candidate),
+        }
+    }
+}
+
+impl SearchFilter {
+    /// `q` matches anywhere in keys and values; `key` matches entry keys or inline `label:` fields exactly
+    /// (ignoring case). With `regex`, both are treated as case-insensitive regular expressions.
+    pub fn new(
+        q: Option<&str>,
+        key: Option<&str>,
+        section: Option<&str>,
+        regex: bool,
+    ) -> Result<Self, AppError> {
+        let q = non_empty(q);
+        let key = non_empty(key);
+        let section = non_empty(section).map(str::to_lowercase);
+        if q.is_none() && key.is_none() && section.is_none() {
+            return Err(AppError::InvalidQuery(
+                "at least one of 'q', 'key' or 'section' is required".to_string(),
+            ));
+        }
+
+        let text = q
+            .map(|q| {
+                if regex {
+                    compile(q)
+                } else {
+                    compile(&regex::escape(q))
+                }
+            })
+            .transpose()?;
+        let key = key
+            .map(|key| {
+                if regex {
+                    compile(key).map(KeyMatcher::Pattern)
+                } else {
+                    Ok(KeyMatcher::Exact(key.to_lowercase()))
+                }
+            })
+            .transpose()?;
+
+        Ok(Self { text, key, section })
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn compile(pattern: &str) -> Result<Regex, AppError> {
+    if pattern.len() > SEARCH_MAX_PATTERN_LEN {
+        return Err(AppError::InvalidQuery(format!(
+            "search pattern longer than {SEARCH_MAX_PATTERN_LEN} bytes"
+        )));
+    }
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(SEARCH_REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|err| AppError::InvalidQuery(format!("invalid search pattern: {err}")))
+}
+
+pub fn search(report: &SystemReport, filter: &SearchFilter) -> SearchResults {
+    let mut hits = Vec::new();
+
+    for (section_index, section) in report.sections.iter().enumerate() {
+        if filter
+            .section
+            .as_deref()
+            .is_some_and(|title| section.title.to_lowercase() != title)
+        {
+            continue;
+        }
+
+        for entry in &section.entries {
+            let mut highlights = Vec::new();
+
+            if let Some(matcher) = &filter.key {
+                let before = highlights.len();
+                if matcher.matches(&entry.key) {
+                    highlights.push(highlight(
+                        HighlightField::Key,
+                        &entry.key,
+                        0,
+                        entry.key.len(),
+                    ));
+                }
+                for (start, label) in inline_labels(&entry.value) {
+                    if matcher.matches(label) {
+                        highlights.push(highlight(
+                            HighlightField::Value,
+                            &entry.value,
+                            start,
+                            start + label.len() + 1,
+                        ));
+                    }
+                }
+                if highlights.len() == before {
+                    continue;
+                }
+            }
+
+            if let Some(text) = &filter.text {
+                let before = highlights.len();
+                for (field, haystack) in [
+                    (HighlightField::Key, &entry.key),
+                    (HighlightField::Value, &entry.value),
+                ] {
+                    highlights.extend(
+                        text.find_iter(haystack)
+                            .filter(|found| !found.is_empty())
+                            .map(|found| highlight(field, haystack, found.start(), found.end())),
+                    );
+                }
+                if highlights.len() == before {
+                    continue;
+                }
+            }
+
+            highlights.sort_by_key(|mark| (mark.field, mark.start));
+            hits.push(SearchHit {
+                section: section.title.clone(),
+                section_index,
+                key: entry.key.clone(),
+                value: entry.value.clone(),
+                highlights,
+            });
+        }
+    }
+
+    let total = hits.len();
+    hits.truncate(SEARCH_MAX_RESULTS);
+    SearchResults {
+        total,
+        truncated: total > hits.len(),
+        hits,
+    }
+}
+
+/// Inline `label:` tokens of an entry value with their byte offsets, as split by `labelled_fields`.
+fn inline_labels(value: &str) -> impl Iterator<Item = (usize, &str)> {
+    value.split_whitespace().filter_map(move |token| {
+        let label = token.strip_suffix(':').filter(|label| !label.is_empty())?;
+        // split_whitespace yields subslices of `value`, so the pointer difference is the byte offset.
+        Some((token.as_ptr() as usize - value.as_ptr() as usize, label))
+    })
+}
+
+/// Converts a byte range into UTF-16 offsets, which is what `String.prototype.slice` expects.
+fn highlight(field: HighlightField, text: &str, start: usize, end: usize) -> Highlight {
+    let start_utf16 = text[..start].encode_utf16().count();
+    Highlight {
+        field,
+        start: start_utf16,
+        end: start_utf16 + text[start..end].encode_utf16().count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SearchFilter, search};
+    use crate::models::HighlightField;
+    use crate::services::{InxiMode, parser::parse_system_report};
+
+    const SAMPLE: &str = "Graphics:\n  Device-1: NVIDIA GA104 driver: nvidia v: 550\n  Display: wayland driver: X: loaded: nvidia\nDrives:\n  ID-1: /dev/nvme0n1 vendor: Samsung size: 1.82 TiB\n";
+
+    #[test]
+    fn matches_text_with_offsets_and_keys_with_section_context() {
+        let report = parse_system_report(SAMPLE, InxiMode::Basic).expect("report");
+
+        let nvme = search(
+            &report,
+            &SearchFilter::new(Some("NVMe"), None, None, false).unwrap(),
+        );
+        assert_eq!(nvme.total, 1);
+        assert_eq!(nvme.hits[0].section, "Drives");
+        assert_eq!(nvme.hits[0].section_index, 1);
+        let mark = &nvme.hits[0].highlights[0];
+        assert_eq!(mark.field, HighlightField::Value);
+        assert_eq!(&nvme.hits[0].value[mark.start..mark.end], "nvme");
+
+        let drivers = SearchFilter::new(None, Some("driver"), Some("graphics"), false).unwrap();
+        assert_eq!(search(&report, &drivers).total, 2);
+
+        let pattern = SearchFilter::new(Some(r"v: \d+"), None, None, true).unwrap();
+        assert_eq!(search(&report, &pattern).hits[0].key, "Device-1");
+    }
+
+    #[test]
+    fn rejects_empty_and_invalid_queries() {
+        assert!(SearchFilter::new(Some("  "), None, None, false).is_err());
+        assert!(SearchFilter::new(Some("(unclosed"), None, None, true).is_err());
+        assert!(SearchFilter::new(Some("(unclosed"), None, None, false).is_ok());
+    }
+}
@@ -0,0 +1,480 @@
+// Responsibility: Persist saved reports as one JSON file per snapshot and serve listings, lookups, edits and deletes.
+// Design reasoning: Plain files in a data directory need no database; an in-memory index of summaries keeps listing cheap.
+// Extension guidance: Keep the on-disk format equal to `models::Snapshot`; new fields need `#[serde(default)]` to load older files.
+// Security considerations: File names are parsed UUIDs only, the directory is private (0700) and files are written atomically (0600).
+
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::{self, Write};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{SecondsFormat, Utc};
+use uuid::Uuid;
+
+use crate::config::{
+    DATA_DIR_ENV, SNAPSHOT_INTERVAL_ENV, SNAPSHOT_LABEL_MAX_CHARS, SNAPSHOT_MIN_INTERVAL,
+    SNAPSHOT_NOTES_MAX_CHARS, SNAPSHOT_SCHEDULE_KEEP, SNAPSHOT_SCHEDULE_MODE,
+};
+use crate::error::AppError;
+use crate::models::{
+    Snapshot, SnapshotPage, SnapshotSummary, SnapshotTrigger, SnapshotUpdate, SystemReport,
+};
+use crate::services::{InxiMode, InxiService};
+
+#[derive(Debug)]
+pub struct SnapshotStore {
+    /// `{data dir}/snapshots`, or why no data directory could be chosen.
+    dir: Result<PathBuf, String>,
+    /// Summaries of every stored snapshot, oldest first; `None` until the directory has been opened.
+    index: Mutex<Option<Vec<SnapshotSummary>>>,
+}
+
+impl SnapshotStore {
+    /// Resolves `{data dir}/snapshots` without touching the disk; the directory is opened on first use.
+    pub fn from_env() -> Self {
+        Self {
+            dir: data_dir().map(|dir| dir.join("snapshots")),
+            index: Mutex::new(None),
+        }
+    }
+
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref().ok()
+    }
+
+    /// Runs `op` on the blocking pool, as every store operation may create, read or write files.
+    pub async fn blocking<T: Send + 'static>(
+        self: &Arc<Self>,
+        op: impl FnOnce(&Self) -> Result<T, AppError> + Send + 'static,
+    ) -> Result<T, AppError> {
+        let store = Arc::clone(self);
+        tokio::task::spawn_blocking(move || op(&store))
+            .await
+            .map_err(|err| AppError::Io(io::Error::other(err)))?
+    }
+
+    pub fn count(&self) -> Result<usize, AppError> {
+        self.with_index(|_, index| Ok(index.len()))
+    }
+
+    /// Stores `report` under a new snapshot ID; scheduled saves prune the oldest scheduled snapshots.
+    pub fn save(
+        &self,
+        report: SystemReport,
+        trigger: SnapshotTrigger,
+        label: Option<String>,
+        notes: Option<String>,
+    ) -> Result<SnapshotSummary, AppError> {
+        let snapshot = Snapshot {
+            id: Uuid::new_v4().to_string(),
+            saved_utc: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            trigger,
+            label: annotation(label, "label", SNAPSHOT_LABEL_MAX_CHARS, false)?,
+            notes: annotation(notes, "notes", SNAPSHOT_NOTES_MAX_CHARS, true)?,
+            report,
+        };
+
+        self.with_index(|dir, index| {
+            write_snapshot(dir, &snapshot)?;
+            let saved = summary(&snapshot);
+            index.push(saved.clone());
+            if trigger == SnapshotTrigger::Schedule {
+                prune_scheduled(dir, index);
+            }
+            Ok(saved)
+        })
+    }
+
+    /// Newest-first page of snapshots whose label contains `label` (case-insensitive).
+    pub fn list(
+        &self,
+        offset: usize,
+        limit: usize,
+        label: Option<&str>,
+    ) -> Result<SnapshotPage, AppError> {
+        let needle = label.map(str::to_lowercase);
+        self.with_index(|_, index| {
+            let matching = index
+                .iter()
+                .rev()
+                .filter(|snapshot| match &needle {
+                    Some(needle) => snapshot
+                        .label
+                        .as_deref()
+                        .is_some_and(|label| label.to_lowercase().contains(needle)),
+                    None => true,
+                })
+                .collect::<Vec<_>>();
+
+            Ok(SnapshotPage {
+                total: matching.len(),
+                offset,
+                limit,
+                snapshots: matching
+                    .into_iter()
+                    .skip(offset)
+                    .take(limit)
+                    .cloned()
+                    .collect(),
+            })
+        })
+    }
+
+    pub fn get(&self, id: &str) -> Result<Snapshot, AppError> {
+        let id = snapshot_id(id)?;
+        self.with_index(|dir, index| {
+            if !index.iter().any(|snapshot| snapshot.id == id) {
+                return Err(not_found(&id));
+            }
+            read_snapshot(&snapshot_path(dir, &id))
+        })
+    }
+
+    /// Replaces the annotations given in `update`; an empty string clears one.
+    pub fn update(&self, id: &str, update: SnapshotUpdate) -> Result<SnapshotSummary, AppError> {
+        let id = snapshot_id(id)?;
+        self.with_index(|dir, index| {
+            let position = index
+                .iter()
+                .position(|snapshot| snapshot.id == id)
+                .ok_or_else(|| not_found(&id))?;
+
+            let mut snapshot = read_snapshot(&snapshot_path(dir, &id))?;
+            if update.label.is_some() {
+                snapshot.label =
+                    annotation(update.label, "label", SNAPSHOT_LABEL_MAX_CHARS, false)?;
+            }
+            if update.notes.is_some() {
+                snapshot.notes = annotation(update.notes, "notes", SNAPSHOT_NOTES_MAX_CHARS, true)?;
+            }
+            write_snapshot(dir, &snapshot)?;
+            index[position] = summary(&snapshot);
+            Ok(index[position].clone())
+        })
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), AppError> {
+        let id = snapshot_id(id)?;
+        self.with_index(|dir, index| {
+            let position = index
+                .iter()
+                .position(|snapshot| snapshot.id == id)
+                .ok_or_else(|| not_found(&id))?;
+            fs::remove_file(snapshot_path(dir, &id))?;
+            index.remove(position);
+            Ok(())
+        })
+    }
+
+    /// Runs `op` on the index, opening the directory first if no earlier call managed to.
+    fn with_index<T>(
+        &self,
+        op: impl FnOnce(&Path, &mut Vec<SnapshotSummary>) -> Result<T, AppError>,
+    ) -> Result<T, AppError> {
+        let dir = self
+            .dir
+            .as_deref()
+            .map_err(|reason| AppError::StorageUnavailable(reason.clone()))?;
+        let mut index = self
+            .index
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let index = match &mut *index {
+            Some(index) => index,
+            slot @ None => slot.insert(load_index(dir).map_err(|err| {
+                AppError::StorageUnavailable(format!("{}: {err}", dir.display()))
+            })?),
+        };
+        op(dir, index)
+    }
+}
+
+/// The `INXI_DASH_SNAPSHOT_INTERVAL` schedule, or `None` when scheduled snapshots are off.
+pub fn schedule_interval() -> Result<Option<Duration>, AppError> {
+    let Ok(value) = std::env::var(SNAPSHOT_INTERVAL_ENV) else {
+        return Ok(None);
+    };
+    let seconds = value.trim().parse::<u64>().map_err(|_| {
+        AppError::Config(format!(
+            "{SNAPSHOT_INTERVAL_ENV} must be a number of seconds"
+        ))
+    })?;
+    match Duration::from_secs(seconds) {
+        interval if interval.is_zero() => Ok(None),
+        interval if interval < SNAPSHOT_MIN_INTERVAL => Err(AppError::Config(format!(
+            "{SNAPSHOT_INTERVAL_ENV} must be 0 or at least {} seconds",
+            SNAPSHOT_MIN_INTERVAL.as_secs()
+        ))),
+        interval => Ok(Some(interval)),
+    }
+}
+
+/// Saves a `SNAPSHOT_SCHEDULE_MODE` report every `interval` until shutdown begins.
+pub async fn run_schedule(
+    store: Arc<SnapshotStore>,
+    service: Arc<InxiService>,
+    interval: Duration,
+) {
+    let mode = match InxiMode::parse(SNAPSHOT_SCHEDULE_MODE) {
+        Ok(mode) => mode,
+        Err(err) => {
+            tracing::error!(error = %err, "scheduled snapshots disabled");
+            return;
+        }
+    };
+    let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = service.shutting_down() => return,
+        }
+        let saved = match service.run(mode).await {
+            Ok(report) => {
+                store
+                    .blocking(move |store| {
+                        store.save(report, SnapshotTrigger::Schedule, None, None)
+                    })
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+        match saved {
+            Ok(snapshot) => {
+                tracing::info!(id = %snapshot.id, mode = %mode, "saved scheduled snapshot")
+            }
+            Err(err) => tracing::warn!(error = %err, mode = %mode, "scheduled snapshot failed"),
+        }
+    }
+}
+
+/// Never falls back to a relative path, which would depend on the directory the server was started from.
+fn data_dir() -> Result<PathBuf, String> {
+    let var = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty());
+    if let Some(dir) = var(DATA_DIR_ENV) {
+        return Ok(PathBuf::from(dir));
+    }
+    if let Some(data_home) = var("XDG_DATA_HOME") {
+        return Ok(PathBuf::from(data_home).join("inxi-dash"));
+    }
+    var("HOME")
+        .map(|home| PathBuf::from(home).join(".local/share/inxi-dash"))
+        .ok_or_else(|| format!("set {DATA_DIR_ENV}; neither XDG_DATA_HOME nor HOME is set"))
+}
+
+/// Creates the directory when missing and indexes the snapshots in it; unreadable files are skipped.
+fn load_index(dir: &Path) -> io::Result<Vec<SnapshotSummary>> {
+    DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+
+    let mut index = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(id) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".json"))
+            .filter(|id| Uuid::parse_str(id).is_ok())
+        else {
+            continue;
+        };
+        match read_snapshot(&path) {
+            Ok(snapshot) if snapshot.id == id => index.push(summary(&snapshot)),
+            Ok(_) => {
+                tracing::warn!(path = %path.display(), "skipping snapshot whose ID does not match its file name")
+            }
+            Err(err) => {
+                tracing::warn!(path = %path.display(), error = %err, "skipping unreadable snapshot")
+            }
+        }
+    }
+    index.sort_by(|a, b| (&a.saved_utc, &a.id).cmp(&(&b.saved_utc, &b.id)));
+    Ok(index)
+}
+
+/// Writes to a hidden temporary file first so a crash never leaves a half-written snapshot.
+fn write_snapshot(dir: &Path, snapshot: &Snapshot) -> Result<(), AppError> {
+    let body = serde_json::to_vec_pretty(snapshot).map_err(io::Error::other)?;
+    let temporary = dir.join(format!(".{}.json.tmp", snapshot.id));
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(&temporary)?;
+    file.write_all(&body)?;
+    file.sync_all()?;
+    fs::rename(&temporary, snapshot_path(dir, &snapshot.id))?;
+    Ok(())
+}
+
+fn prune_scheduled(dir: &Path, index: &mut Vec<SnapshotSummary>) {
+    let scheduled = index
+        .iter()
+        .filter(|snapshot| snapshot.trigger == SnapshotTrigger::Schedule)
+        .count();
+    for _ in SNAPSHOT_SCHEDULE_KEEP..scheduled {
+        let Some(position) = index
+            .iter()
+            .position(|snapshot| snapshot.trigger == SnapshotTrigger::Schedule)
+        else {
+            break;
+        };
+        let oldest = index.remove(position);
+        if let Err(err) = fs::remove_file(snapshot_path(dir, &oldest.id)) {
+            tracing::warn!(id = %oldest.id, error = %err, "failed to prune scheduled snapshot");
+        }
+    }
+}
+
+fn snapshot_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.json"))
+}
+
+fn read_snapshot(path: &Path) -> Result<Snapshot, AppError> {
+    let body = fs::read(path)?;
+    serde_json::from_slice(&body).map_err(|err| {
+        AppError::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {err}", path.display()),
+        ))
+    })
+}
+
+fn summary(snapshot: &Snapshot) -> SnapshotSummary {
+    SnapshotSummary {
+        id: snapshot.id.clone(),
+        saved_utc: snapshot.saved_utc.clone(),
+        trigger: snapshot.trigger,
+        label: snapshot.label.clone(),
+        notes: snapshot.notes.clone(),
+        mode: snapshot.report.mode.clone(),
+        report_id: snapshot.report.id.clone(),
+        collected_utc: snapshot.report.metadata.collected_utc.clone(),
+        sections: snapshot.report.sections.len(),
+    }
+}
+
+/// Trims a label or note; blank values become `None`, and only notes may span lines.
+fn annotation(
+    value: Option<String>,
+    name: &str,
+    max_chars: usize,
+    multiline: bool,
+) -> Result<Option<String>, AppError> {
+    let Some(value) = value.map(|value| value.trim().to_string()) else {
+        return Ok(None);
+    };
+    if value.chars().count() > max_chars {
+        return Err(AppError::InvalidQuery(format!(
+            "`{name}` is longer than {max_chars} characters"
+        )));
+    }
+    if value
+        .chars()
+        .any(|ch| ch.is_control() && !(multiline && matches!(ch, '\n' | '\t')))
+    {
+        return Err(AppError::InvalidQuery(format!(
+            "`{name}` contains control characters"
+        )));
+    }
+    Ok(Some(value).filter(|value| !value.is_empty()))
+}
+
+/// Normalizes a snapshot ID; anything but a UUID is rejected before it can reach a file path.
+fn snapshot_id(id: &str) -> Result<String, AppError> {
+    Uuid::parse_str(id)
+        .map(|id| id.to_string())
+        .map_err(|_| AppError::InvalidQuery("snapshot IDs are UUIDs".to_string()))
+}
+
+fn not_found(id: &str) -> AppError {
+    AppError::NotFound(format!("snapshot '{id}' does not exist"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotStore;
+    use crate::error::AppError;
+    use crate::models::{SnapshotTrigger, SnapshotUpdate};
+    use crate::services::{InxiMode, parser::parse_system_report};
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    fn open(dir: PathBuf) -> Result<SnapshotStore, AppError> {
+        let store = SnapshotStore {
+            dir: Ok(dir),
+            index: Mutex::new(None),
+        };
+        store.count().map(|_| store)
+    }
+
+    #[test]
+    fn saves_lists_updates_and_deletes_snapshots() {
+        let dir = std::env::temp_dir().join(format!("inxi-dash-test-{}", uuid::Uuid::new_v4()));
+        let store = open(dir.clone()).expect("store");
+        let report = parse_system_report("System:\n  Host: box Kernel: 6.12.68\n", InxiMode::Basic)
+            .expect("report");
+
+        let first = store
+            .save(
+                report.clone(),
+                SnapshotTrigger::Manual,
+                Some(" Before upgrade ".to_string()),
+                None,
+            )
+            .expect("save");
+        let second = store
+            .save(
+                report,
+                SnapshotTrigger::Schedule,
+                None,
+                Some("nightly\n".to_string()),
+            )
+            .expect("save");
+        assert_eq!(first.label.as_deref(), Some("Before upgrade"));
+
+        let page = store.list(0, 10, None).expect("list");
+        assert_eq!(page.total, 2);
+        assert_eq!(page.snapshots[0].id, second.id);
+        assert_eq!(
+            store.list(0, 10, Some("UPGRADE")).expect("list").snapshots,
+            vec![first.clone()]
+        );
+
+        let updated = store
+            .update(
+                &first.id,
+                SnapshotUpdate {
+                    label: Some(String::new()),
+                    notes: Some("kept".to_string()),
+                },
+            )
+            .expect("update");
+        assert_eq!(updated.label, None);
+        assert!(store.get(&second.id.to_uppercase()).is_ok());
+        assert!(store.get("../etc/passwd").is_err());
+
+        // A reopened store indexes the same files.
+        let reopened = open(dir.clone()).expect("reopen");
+        assert_eq!(
+            reopened.get(&first.id).expect("get").notes.as_deref(),
+            Some("kept")
+        );
+        reopened.delete(&second.id).expect("delete");
+        assert_eq!(reopened.count().expect("count"), 1);
+        assert!(reopened.get(&second.id).is_err());
+
+        std::fs::remove_dir_all(&dir).expect("cleanup");
+
+        // A directory that cannot be created is reported as unavailable storage.
+        std::fs::write(&dir, b"").expect("file");
+        let blocked = open(dir.join("snapshots"));
+        assert_eq!(
+            blocked.map(|_| ()).map_err(|err| err.code()),
+            Err("storage_unavailable")
+        );
+        std::fs::remove_file(dir).expect("cleanup");
+    }
+}
@@ -14,7 +14,7 @@ pub fn strip_ansi(input: &str) -> String {
                 if let Some(next) = chars.peek() {
                     if *next == '[' {
                         chars.next();
-                        while let Some(csi) = chars.next() {
+                        for csi in chars.by_ref() {
                             if ('@'..='~').contains(&csi) {
                                 break;
                             }
@@ -0,0 +1,69 @@
+// Responsibility: Split inxi entry values into the inline `label: value` fields they are made of.
+// Design reasoning: inxi packs several facts into one line (`Host: x Kernel: 6.1 arch: x86_64`), so lookups need field-level access.
+// Extension guidance: Keep the tokenizer whitespace-based; callers decide how to match labels (case, aliases).
+// Security considerations: Pure string slicing over already-sanitized text; nothing is evaluated or re-escaped here.
+
+/// One inline field of an entry value. The leading text before the first label has no label.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Field {
+    pub label: Option<String>,
+    pub value: String,
+}
+
+/// Splits `6.12 arch: x86_64 bits: 64` into an unlabelled `6.12` followed by `arch` and `bits` fields.
+pub fn labelled_fields(value: &str) -> Vec<Field> {
+    let mut fields = Vec::new();
+    let mut label: Option<String> = None;
+    let mut words: Vec<&str> = Vec::new();
+
+    for token in value.split_whitespace() {
+        if token.len() > 1 && token.ends_with(':') {
+            if label.is_some() || !words.is_empty() {
+                fields.push(Field {
+                    label: label.take(),
+                    value: words.join(" "),
+                });
+                words.clear();
+            }
+            label = Some(token.trim_end_matches(':').to_string());
+            continue;
+        }
+        words.push(token);
+    }
+
+    if label.is_some() || !words.is_empty() {
+        fields.push(Field {
+            label,
+            value: words.join(" "),
+        });
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Field, labelled_fields};
+
+    #[test]
+    fn splits_leading_text_and_labels() {
+        let fields = labelled_fields("workstation Kernel: 6.12.68 arch: x86_64");
+        assert_eq!(
+            fields,
+            vec![
+                Field {
+                    label: None,
+                    value: "workstation".to_string()
+                },
+                Field {
+                    label: Some("Kernel".to_string()),
+                    value: "6.12.68".to_string()
+                },
+                Field {
+                    label: Some("arch".to_string()),
+                    value: "x86_64".to_string()
+                },
+            ]
+        );
+    }
+}
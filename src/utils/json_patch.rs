@@ -0,0 +1,81 @@
+// Responsibility: Compute RFC 6902 style patches (add/remove/replace) between two JSON documents.
+// Design reasoning: Streaming clients already hold the previous report, so sending only changed paths keeps events small.
+// Extension guidance: Arrays are diffed index-wise; add keyed matching here if sections start reordering between runs.
+// Security considerations: Patches are derived from already-restricted reports and only reference paths inside them.
+
+use serde_json::{Value, json};
+
+/// Returns the operations that turn `old` into `new`; empty when the documents are equal.
+pub fn diff(old: &Value, new: &Value) -> Vec<Value> {
+    let mut ops = Vec::new();
+    walk(String::new(), old, new, &mut ops);
+    ops
+}
+
+fn walk(path: String, old: &Value, new: &Value, ops: &mut Vec<Value>) {
+    match (old, new) {
+        (Value::Object(before), Value::Object(after)) => {
+            for (key, value) in before {
+                let child = format!("{path}/{}", escape_pointer(key));
+                match after.get(key) {
+                    Some(next) => walk(child, value, next, ops),
+                    None => ops.push(json!({ "op": "remove", "path": child })),
+                }
+            }
+            for (key, value) in after {
+                if !before.contains_key(key) {
+                    let child = format!("{path}/{}", escape_pointer(key));
+                    ops.push(json!({ "op": "add", "path": child, "value": value }));
+                }
+            }
+        }
+        (Value::Array(before), Value::Array(after)) => {
+            let shared = before.len().min(after.len());
+            for index in 0..shared {
+                walk(
+                    format!("{path}/{index}"),
+                    &before[index],
+                    &after[index],
+                    ops,
+                );
+            }
+            for (index, value) in after.iter().enumerate().skip(shared) {
+                ops.push(json!({ "op": "add", "path": format!("{path}/{index}"), "value": value }));
+            }
+            // Remove from the end so earlier indices stay valid while the patch is applied.
+            for index in (shared..before.len()).rev() {
+                ops.push(json!({ "op": "remove", "path": format!("{path}/{index}") }));
+            }
+        }
+        _ if old != new => ops.push(json!({ "op": "replace", "path": path, "value": new })),
+        _ => {}
+    }
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+    use serde_json::json;
+
+    #[test]
+    fn emits_minimal_operations() {
+        let old = json!({ "timestamp": 1, "sections": [{ "title": "CPU" }, { "title": "GPU" }], "a/b": 1 });
+        let new =
+            json!({ "timestamp": 2, "sections": [{ "title": "CPU" }], "a/b": 2, "extra": true });
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                json!({ "op": "replace", "path": "/a~1b", "value": 2 }),
+                json!({ "op": "remove", "path": "/sections/1" }),
+                json!({ "op": "replace", "path": "/timestamp", "value": 2 }),
+                json!({ "op": "add", "path": "/extra", "value": true }),
+            ]
+        );
+        assert!(diff(&new, &new).is_empty());
+    }
+}
@@ -4,5 +4,10 @@
 // Security considerations: Helpers must not rely on untrusted data when constructing command strings or file paths.
 
 pub mod ansi;
+pub mod fields;
+pub mod json_patch;
+pub mod path;
 
 pub use ansi::strip_ansi;
+pub use fields::labelled_fields;
+pub use path::find_in_path;
@@ -0,0 +1,18 @@
+// Responsibility: Locate executables on PATH without spawning a process.
+// Design reasoning: Readiness probes run every few seconds, so a filesystem lookup is far cheaper than running `inxi --version`.
+// Extension guidance: Reuse this for other optional helper binaries before invoking them.
+// Security considerations: Only reports what PATH resolves to; callers still execute by name through tokio::process.
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+pub fn find_in_path(binary: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(binary))
+        .find(|candidate| {
+            candidate
+                .metadata()
+                .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+        })
+}
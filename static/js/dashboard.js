@@ -1,6 +1,8 @@
 (function () {
   const THEME_KEY = "inxi-dashboard-theme";
   const MODE_QUERY = "inxi-dashboard-mode";
+  const LIVE_KEY = "inxi-dashboard-live";
+  const HISTORY_PAGE_SIZE = 10;
 
   const modeSelect = document.getElementById("mode-select");
   const themeSelect = document.getElementById("theme-select");
@@ -8,21 +10,58 @@
   const statusText = document.getElementById("status-text");
   const componentCards = document.getElementById("component-cards");
   const downloadLink = document.getElementById("download-link");
-
-  if (!modeSelect || !themeSelect || !refreshButton || !statusText || !componentCards) {
+  const exportSelect = document.getElementById("export-format");
+  const forumCopyButton = document.getElementById("forum-copy");
+  const liveToggle = document.getElementById("live-toggle");
+  const searchForm = document.getElementById("search-form");
+  const searchInput = document.getElementById("search-input");
+  const searchResults = document.getElementById("search-results");
+  const snapshotForm = document.getElementById("snapshot-form");
+  const snapshotLabel = document.getElementById("snapshot-label");
+  const snapshotNotes = document.getElementById("snapshot-notes");
+  const snapshotRows = document.getElementById("snapshot-rows");
+  const snapshotTotal = document.getElementById("snapshot-total");
+  const snapshotNewer = document.getElementById("snapshot-newer");
+  const snapshotOlder = document.getElementById("snapshot-older");
+  const diffView = document.getElementById("diff-view");
+  const diffTitle = document.getElementById("diff-title");
+  const diffSummary = document.getElementById("diff-summary");
+  const diffSections = document.getElementById("diff-sections");
+  const diffMarkdownButton = document.getElementById("diff-markdown");
+  const diffCloseButton = document.getElementById("diff-close");
+  const accessRole = document.getElementById("access-role");
+  const loginForm = document.getElementById("login-form");
+  const loginToken = document.getElementById("login-token");
+  const logoutButton = document.getElementById("logout-button");
+
+  if (!modeSelect || !themeSelect || !statusText || !componentCards) {
     return;
   }
 
+  // Exported HTML files embed the report (and icons as data URLs) and run without a server.
+  const embeddedReport = readEmbeddedJson("inxi-report-data");
+  const offlineIcons = readEmbeddedJson("inxi-offline-icons") || {};
+
   const state = {
     mode: modeSelect.value,
     theme: themeSelect.value,
+    report: null,
+    stream: null,
+    offline: Boolean(embeddedReport),
+    // Summary of the stored snapshot on screen; null while showing live data.
+    snapshot: null,
+    historyOffset: 0,
+    // Body of the last /api/v1/diff request, reused for the Markdown download.
+    diffRequest: null,
+    // Whether the role may save and delete snapshots; viewers only list, view and compare them.
+    managesSnapshots: false,
   };
 
   const CARD_CONFIG = [
     {
       id: "system",
       label: "OS & Kernel",
-      icon: "/static/icons/chip.png",
+      icon: "/static/icons/monitor.png",
       sectionKeywords: ["system"],
       keywords: ["kernel", "desktop", "distro", "base", "arch"],
     },
@@ -43,7 +82,7 @@
     {
       id: "memory",
       label: "Memory",
-      icon: "/static/icons/ssd.png",
+      icon: "/static/icons/memory.png",
       sectionKeywords: ["memory", "swap"],
       keywords: ["memory", "ram", "swap", "slot", "dimm", "channel", "ddr"],
     },
@@ -84,6 +123,20 @@
     },
   ];
 
+  function readEmbeddedJson(id) {
+    const element = document.getElementById(id);
+    if (!element) return null;
+    try {
+      return JSON.parse(element.textContent);
+    } catch (_) {
+      return null;
+    }
+  }
+
+  function iconUrl(path) {
+    return offlineIcons[path] || path;
+  }
+
   function setTheme(theme) {
     document.documentElement.setAttribute("theme", theme);
     themeSelect.value = theme;
@@ -105,21 +158,86 @@
   function setMode(mode) {
     state.mode = mode;
     modeSelect.value = mode;
-    downloadLink.href = `/download?mode=${mode}`;
+    updateDownloadLink();
     localStorage.setItem(MODE_QUERY, mode);
   }
 
+  // Exports name the report on screen so the server renders it instead of running inxi again.
+  function exportParams(format) {
+    let params;
+    if (state.snapshot) params = new URLSearchParams({ snapshot: state.snapshot.id });
+    else if (state.report && state.report.id) params = new URLSearchParams({ report: state.report.id });
+    else params = new URLSearchParams({ mode: state.mode });
+    if (format !== "html") params.set("format", format);
+    return params;
+  }
+
+  function updateDownloadLink() {
+    if (!downloadLink) return;
+    downloadLink.href = `/download?${exportParams(exportSelect ? exportSelect.value : "html")}`;
+  }
+
+  function modeAllowed(mode) {
+    const option = Array.from(modeSelect.options).find((item) => item.value === mode);
+    return Boolean(option) && !option.disabled;
+  }
+
   function loadMode() {
     const stored = localStorage.getItem(MODE_QUERY);
-    if (stored) {
+    if (stored && modeAllowed(stored)) {
       setMode(stored);
       return;
     }
 
-    setMode(modeSelect.value);
+    const fallback = Array.from(modeSelect.options).find((item) => !item.disabled);
+    setMode(modeAllowed(modeSelect.value) || !fallback ? modeSelect.value : fallback.value);
+  }
+
+  // Hides modes and snapshot controls the caller's role cannot use. The browser authenticates with the
+  // session cookie from signing in, since fetch links and EventSource cannot send an Authorization header.
+  function loadAccess() {
+    return Promise.all([requestJson("/api/v1/session"), requestJson("/api/v1/modes")])
+      .then(([session, modes]) => {
+        modes.forEach((mode) => {
+          const option = Array.from(modeSelect.options).find((item) => item.value === mode.name);
+          if (!option) return;
+          option.hidden = !mode.allowed;
+          option.disabled = !mode.allowed;
+        });
+        if (accessRole) {
+          accessRole.textContent = `Role: ${session.role}`;
+          accessRole.hidden = false;
+        }
+        if (loginForm) loginForm.hidden = !session.login_available || session.credential !== "anonymous";
+        if (logoutButton) logoutButton.hidden = session.credential !== "session";
+        state.managesSnapshots = session.manages_snapshots;
+        if (snapshotForm) snapshotForm.hidden = !state.managesSnapshots;
+      })
+      .catch((err) => {
+        statusText.textContent = `Unable to load access: ${err.message}`;
+      });
+  }
+
+  function signIn(event) {
+    event.preventDefault();
+    requestJson("/api/v1/session", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ token: loginToken.value }),
+    })
+      .then(() => window.location.reload())
+      .catch((err) => {
+        loginToken.value = "";
+        statusText.textContent = `Sign-in failed: ${err.message}`;
+      });
+  }
+
+  function signOut() {
+    fetch("/api/v1/session", { method: "DELETE" }).finally(() => window.location.reload());
   }
 
   function toggleLoading(isLoading) {
+    if (!refreshButton) return;
     refreshButton.disabled = isLoading;
     refreshButton.textContent = isLoading ? "Refreshing..." : "Refresh";
   }
@@ -133,10 +251,16 @@
 
   function copyToClipboard(text, contextNode) {
     if (navigator.clipboard && navigator.clipboard.writeText) {
-      navigator.clipboard.writeText(text).then(() => indicateCopy(contextNode));
+      navigator.clipboard
+        .writeText(text)
+        .then(() => indicateCopy(contextNode))
+        .catch(() => copyWithSelection(text, contextNode));
       return;
     }
+    copyWithSelection(text, contextNode);
+  }
 
+  function copyWithSelection(text, contextNode) {
     const area = document.createElement("textarea");
     area.value = text;
     area.setAttribute("aria-hidden", "true");
@@ -701,6 +825,7 @@
 
     const article = document.createElement("article");
     article.className = "card component-card shadow-sm";
+    article.dataset.card = card.id;
 
     const header = document.createElement("div");
     header.className = "component-card-header";
@@ -710,7 +835,7 @@
 
     const icon = document.createElement("img");
     icon.className = "insight-icon";
-    icon.src = card.icon;
+    icon.src = iconUrl(card.icon);
     icon.alt = `${card.label} icon`;
 
     const textWrap = document.createElement("div");
@@ -766,11 +891,19 @@
 
     article.appendChild(header);
     article.appendChild(body);
-    componentCards.appendChild(article);
+
+    // Swap an existing card in place so live updates don't reflow the whole grid.
+    const existing = componentCards.querySelector(`[data-card="${card.id}"]`);
+    if (existing) {
+      existing.replaceWith(article);
+    } else {
+      componentCards.appendChild(article);
+    }
   }
 
   function renderSections(report) {
-    componentCards.innerHTML = "";
+    state.report = report;
+    updateDownloadLink();
     const groups = splitEntries(report.sections);
     CARD_CONFIG.forEach((card) => {
       const rawEntries = groups.get(card.id) || [];
@@ -780,8 +913,9 @@
   }
 
   function refreshReport() {
+    state.snapshot = null;
     toggleLoading(true);
-    fetch(`/api/system?mode=${state.mode}`)
+    fetch(`/api/v1/system?mode=${state.mode}`)
       .then((response) => {
         if (!response.ok) {
           return response.json().then((payload) => {
@@ -790,10 +924,7 @@
         }
         return response.json();
       })
-      .then((payload) => {
-        renderSections(payload);
-        updateStatus(payload);
-      })
+      .then(showReport)
       .catch((err) => {
         statusText.textContent = `Unable to refresh: ${err.message}`;
       })
@@ -803,7 +934,532 @@
   function updateStatus(report) {
     const millis = report.timestamp * 1000;
     const when = new Date(millis).toLocaleString();
-    statusText.textContent = `Mode: ${report.mode} · Refreshed ${when}`;
+    if (state.snapshot) {
+      const name = state.snapshot.label ? `"${state.snapshot.label}"` : "snapshot";
+      statusText.textContent = `Mode: ${report.mode} · Viewing ${name} collected ${when} · Refresh for live data`;
+      return;
+    }
+    statusText.textContent = state.offline
+      ? `Mode: ${report.mode} · Collected ${when} · Offline snapshot`
+      : `Mode: ${report.mode} · Refreshed ${when}`;
+  }
+
+  function decodePointer(path) {
+    return path
+      .split("/")
+      .slice(1)
+      .map((token) => token.replace(/~1/g, "/").replace(/~0/g, "~"));
+  }
+
+  // Applies the add/remove/replace subset of RFC 6902 emitted by /api/v1/stream.
+  function applyPatch(report, operations) {
+    const copy = structuredClone(report);
+    operations.forEach((operation) => {
+      const tokens = decodePointer(operation.path);
+      const last = tokens.pop();
+      const parent = tokens.reduce((node, token) => node[token], copy);
+      if (Array.isArray(parent)) {
+        const index = Number(last);
+        if (operation.op === "add") parent.splice(index, 0, operation.value);
+        else if (operation.op === "remove") parent.splice(index, 1);
+        else parent[index] = operation.value;
+      } else if (operation.op === "remove") {
+        delete parent[last];
+      } else {
+        parent[last] = operation.value;
+      }
+    });
+    return copy;
+  }
+
+  function showReport(report) {
+    renderSections(report);
+    updateStatus(report);
+  }
+
+  function stopLive() {
+    if (state.stream) {
+      state.stream.close();
+      state.stream = null;
+    }
+  }
+
+  function startLive() {
+    stopLive();
+    state.snapshot = null;
+    const stream = new EventSource(`/api/v1/stream?mode=${state.mode}`);
+    stream.addEventListener("report", (event) => showReport(JSON.parse(event.data)));
+    // A patch only applies to the report it was computed from. After a manual refresh the report on
+    // screen differs, so reconnect; the new stream starts with a full report.
+    stream.addEventListener("patch", (event) => {
+      const patch = JSON.parse(event.data);
+      if (!state.report || state.report.id !== patch.base) {
+        startLive();
+        return;
+      }
+      showReport(applyPatch(state.report, patch.operations));
+    });
+    stream.addEventListener("error", (event) => {
+      if (event.data) {
+        statusText.textContent = `Live update failed: ${JSON.parse(event.data).message}`;
+      }
+    });
+    state.stream = stream;
+  }
+
+  function setLive(enabled) {
+    if (liveToggle) liveToggle.checked = enabled;
+    localStorage.setItem(LIVE_KEY, enabled ? "on" : "off");
+    if (enabled) startLive();
+    else stopLive();
+  }
+
+  // "key:driver section:Graphics nvme" -> { key: "driver", section: "Graphics", q: "nvme" }
+  function parseSearch(text) {
+    const params = new URLSearchParams({ mode: state.mode });
+    const words = [];
+    text.trim().split(/\s+/).forEach((word) => {
+      const match = word.match(/^(key|section):(.+)$/i);
+      if (match) params.set(match[1].toLowerCase(), match[2]);
+      else if (word) words.push(word);
+    });
+    if (words.length) params.set("q", words.join(" "));
+    return params;
+  }
+
+  function highlightedText(text, marks) {
+    const fragment = document.createDocumentFragment();
+    let cursor = 0;
+    marks.forEach((mark) => {
+      if (mark.start < cursor) return;
+      fragment.appendChild(document.createTextNode(text.slice(cursor, mark.start)));
+      const highlight = document.createElement("mark");
+      highlight.textContent = text.slice(mark.start, mark.end);
+      fragment.appendChild(highlight);
+      cursor = mark.end;
+    });
+    fragment.appendChild(document.createTextNode(text.slice(cursor)));
+    return fragment;
+  }
+
+  function renderSearchResults(results) {
+    searchResults.innerHTML = "";
+    const heading = document.createElement("h4");
+    heading.className = "mb-2";
+    heading.textContent = results.truncated
+      ? `Search results (first ${results.hits.length} of ${results.total})`
+      : `Search results (${results.total})`;
+    searchResults.appendChild(heading);
+
+    if (!results.hits.length) {
+      const empty = document.createElement("p");
+      empty.className = "text-muted italic m-0";
+      empty.textContent = "No entries match this search.";
+      searchResults.appendChild(empty);
+    } else {
+      const table = document.createElement("table");
+      table.className = "report-table";
+      const tbody = document.createElement("tbody");
+      results.hits.forEach((hit) => {
+        const row = document.createElement("tr");
+        const keyCell = document.createElement("td");
+        keyCell.className = "font-semibold text-muted";
+        keyCell.appendChild(document.createTextNode(`${hit.section} / `));
+        keyCell.appendChild(highlightedText(hit.key, hit.highlights.filter((mark) => mark.field === "key")));
+        const valueCell = document.createElement("td");
+        valueCell.className = "entry-value";
+        valueCell.appendChild(highlightedText(hit.value, hit.highlights.filter((mark) => mark.field === "value")));
+        row.appendChild(keyCell);
+        row.appendChild(valueCell);
+        row.appendChild(createActionCell(hit.value, row));
+        tbody.appendChild(row);
+      });
+      table.appendChild(tbody);
+      searchResults.appendChild(table);
+    }
+    searchResults.hidden = false;
+  }
+
+  // The server renders the forum text so trimming and default redaction match `/download?format=bbcode`.
+  // The clipboard write starts inside the click handler with the export still pending: Safari and
+  // Firefox reject writes made after an awaited fetch, because the user gesture has expired by then.
+  function copyForForum() {
+    forumCopyButton.disabled = true;
+    const exported = fetch(`/download?${exportParams("bbcode")}`).then((response) =>
+      response.text().then((body) => {
+        if (!response.ok) {
+          let message = "Export failed";
+          try {
+            message = JSON.parse(body).message || message;
+          } catch (_) {
+            // Non-JSON error bodies keep the generic message.
+          }
+          throw new Error(message);
+        }
+        return body;
+      })
+    );
+    let copied;
+    if (window.ClipboardItem && navigator.clipboard && navigator.clipboard.write) {
+      const item = new ClipboardItem({
+        "text/plain": exported.then((body) => new Blob([body], { type: "text/plain" })),
+      });
+      copied = navigator.clipboard
+        .write([item])
+        .then(() => exported)
+        .then((body) => {
+          indicateCopy(forumCopyButton);
+          return body;
+        })
+        // A failed export rejects with its own message; a refused write falls back to a selection copy.
+        .catch(() =>
+          exported.then((body) => {
+            copyWithSelection(body, forumCopyButton);
+            return body;
+          })
+        );
+    } else {
+      copied = exported.then((body) => {
+        copyToClipboard(body, forumCopyButton);
+        return body;
+      });
+    }
+    copied
+      .then((body) => {
+        statusText.textContent = `Copied ${Array.from(body).length} characters of BBCode for a forum post`;
+      })
+      .catch((err) => {
+        statusText.textContent = `Unable to copy for forum: ${err.message}`;
+      })
+      .finally(() => {
+        forumCopyButton.disabled = false;
+      });
+  }
+
+  function textMarks(field, text, needle) {
+    const marks = [];
+    const haystack = text.toLowerCase();
+    let index = haystack.indexOf(needle);
+    while (needle && index !== -1) {
+      marks.push({ field, start: index, end: index + needle.length });
+      index = haystack.indexOf(needle, index + needle.length);
+    }
+    return marks;
+  }
+
+  function labelMarks(value, key) {
+    const marks = [];
+    const token = /\S+/g;
+    let match;
+    while ((match = token.exec(value))) {
+      const word = match[0];
+      if (word.length > 1 && word.endsWith(":") && word.slice(0, -1).toLowerCase() === key) {
+        marks.push({ field: "value", start: match.index, end: match.index + word.length });
+      }
+    }
+    return marks;
+  }
+
+  // Mirrors /api/v1/search (without regex mode) against the embedded report.
+  function searchOffline(text) {
+    const params = parseSearch(text);
+    const q = (params.get("q") || "").toLowerCase();
+    const key = (params.get("key") || "").toLowerCase();
+    const section = (params.get("section") || "").toLowerCase();
+    const hits = [];
+
+    state.report.sections.forEach((reportSection, sectionIndex) => {
+      if (section && reportSection.title.toLowerCase() !== section) return;
+      reportSection.entries.forEach((entry) => {
+        const highlights = [];
+        if (key) {
+          if (entry.key.toLowerCase() === key) {
+            highlights.push({ field: "key", start: 0, end: entry.key.length });
+          }
+          highlights.push(...labelMarks(entry.value, key));
+          if (!highlights.length) return;
+        }
+        if (q) {
+          const found = textMarks("key", entry.key, q).concat(textMarks("value", entry.value, q));
+          if (!found.length) return;
+          highlights.push(...found);
+        }
+        highlights.sort((a, b) => (a.field === b.field ? a.start - b.start : a.field === "key" ? -1 : 1));
+        hits.push({
+          section: reportSection.title,
+          section_index: sectionIndex,
+          key: entry.key,
+          value: entry.value,
+          highlights,
+        });
+      });
+    });
+
+    return { total: hits.length, truncated: false, hits };
+  }
+
+  function runSearch(text) {
+    if (!text.trim()) {
+      searchResults.hidden = true;
+      return;
+    }
+    // A stored snapshot is already on the page, so it is searched like an offline export.
+    if (state.offline || state.snapshot) {
+      renderSearchResults(searchOffline(text));
+      return;
+    }
+    fetch(`/api/v1/search?${parseSearch(text)}`)
+      .then((response) =>
+        response.json().then((payload) => {
+          if (!response.ok) throw new Error(payload.message || "Search failed");
+          return payload;
+        })
+      )
+      .then(renderSearchResults)
+      .catch((err) => {
+        searchResults.textContent = `Search failed: ${err.message}`;
+        searchResults.hidden = false;
+      });
+  }
+
+  function requestJson(url, options) {
+    return fetch(url, options).then((response) => {
+      if (response.status === 204) return null;
+      return response.json().then((payload) => {
+        if (!response.ok) throw new Error(payload.message || "Request failed");
+        return payload;
+      });
+    });
+  }
+
+  function historyButton(label, className, onClick) {
+    const button = document.createElement("button");
+    button.type = "button";
+    button.className = `btn btn-sm ${className}`;
+    button.textContent = label;
+    button.addEventListener("click", onClick);
+    return button;
+  }
+
+  function renderHistory(page) {
+    snapshotRows.innerHTML = "";
+    if (snapshotTotal) {
+      snapshotTotal.textContent = page.total === 1 ? "1 snapshot" : `${page.total} snapshots`;
+    }
+    if (!page.snapshots.length) {
+      const row = document.createElement("tr");
+      const cell = document.createElement("td");
+      cell.colSpan = 5;
+      cell.className = "text-center text-muted italic";
+      cell.textContent = "No snapshots saved yet.";
+      row.appendChild(cell);
+      snapshotRows.appendChild(row);
+    }
+    page.snapshots.forEach((snapshot) => {
+      const row = document.createElement("tr");
+      const saved = document.createElement("td");
+      saved.textContent = new Date(snapshot.saved_utc).toLocaleString();
+      const label = document.createElement("td");
+      label.textContent = snapshot.label || "—";
+      if (snapshot.notes) label.title = snapshot.notes;
+      const mode = document.createElement("td");
+      mode.textContent = snapshot.mode;
+      const trigger = document.createElement("td");
+      trigger.className = "text-muted";
+      trigger.textContent = snapshot.trigger;
+      const actions = document.createElement("td");
+      actions.className = "flex gap-2 justify-end";
+      actions.appendChild(historyButton("View", "btn-outline", () => viewSnapshot(snapshot)));
+      actions.appendChild(historyButton("Compare", "btn-outline", () => compareSnapshot(snapshot)));
+      if (state.managesSnapshots) {
+        actions.appendChild(historyButton("Delete", "btn-danger", () => deleteSnapshot(snapshot)));
+      }
+      [saved, label, mode, trigger, actions].forEach((cell) => row.appendChild(cell));
+      snapshotRows.appendChild(row);
+    });
+    if (snapshotNewer) snapshotNewer.disabled = page.offset === 0;
+    if (snapshotOlder) snapshotOlder.disabled = page.offset + page.snapshots.length >= page.total;
+  }
+
+  function loadHistory(offset) {
+    if (!snapshotRows) return;
+    state.historyOffset = Math.max(offset, 0);
+    const params = new URLSearchParams({ offset: state.historyOffset, limit: HISTORY_PAGE_SIZE });
+    requestJson(`/api/v1/snapshots?${params}`)
+      .then(renderHistory)
+      .catch((err) => {
+        statusText.textContent = `Unable to load snapshot history: ${err.message}`;
+      });
+  }
+
+  // Saves the report on screen when the server still holds it, otherwise a fresh collection.
+  function saveSnapshot(event) {
+    event.preventDefault();
+    const body = state.report && state.report.id && !state.snapshot
+      ? { report: state.report.id }
+      : { mode: state.mode };
+    if (snapshotLabel && snapshotLabel.value.trim()) body.label = snapshotLabel.value;
+    if (snapshotNotes && snapshotNotes.value.trim()) body.notes = snapshotNotes.value;
+    requestJson("/api/v1/snapshots", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(body),
+    })
+      .then((saved) => {
+        snapshotForm.reset();
+        statusText.textContent = `Saved snapshot${saved.label ? ` "${saved.label}"` : ""}`;
+        loadHistory(0);
+      })
+      .catch((err) => {
+        statusText.textContent = `Unable to save snapshot: ${err.message}`;
+      });
+  }
+
+  function viewSnapshot(summary) {
+    requestJson(`/api/v1/snapshots/${encodeURIComponent(summary.id)}`)
+      .then((snapshot) => {
+        // Live updates would replace the stored report, so they stop while it is shown.
+        setLive(false);
+        state.snapshot = summary;
+        state.mode = snapshot.report.mode;
+        modeSelect.value = snapshot.report.mode;
+        showReport(snapshot.report);
+      })
+      .catch((err) => {
+        statusText.textContent = `Unable to load snapshot: ${err.message}`;
+      });
+  }
+
+  function diffCell(text, className) {
+    const cell = document.createElement("td");
+    cell.className = `entry-value ${className}`;
+    if (text === null || text === undefined) {
+      cell.classList.add("text-muted");
+      cell.textContent = "—";
+    } else {
+      cell.textContent = text;
+    }
+    return cell;
+  }
+
+  // Old and new columns side by side; modified entries list only the fields that changed, the full value is in the tooltip.
+  function renderDiff(diff) {
+    diffSections.innerHTML = "";
+    const { added, removed, modified, ignored } = diff.summary;
+    diffTitle.textContent = `Changes since ${diff.old.collected_utc || "the snapshot"}`;
+    diffSummary.textContent = `${added} added · ${removed} removed · ${modified} modified`
+      + (ignored ? ` · ${ignored} more differed only in ignored fields (${diff.rules.join(", ")})` : "");
+    if (!diff.sections.length) {
+      const empty = document.createElement("p");
+      empty.className = "text-muted italic m-0";
+      empty.textContent = "No differences.";
+      diffSections.appendChild(empty);
+    }
+    diff.sections.forEach((section) => {
+      const heading = document.createElement("h4");
+      heading.className = "mt-3 mb-2";
+      heading.textContent = section.kind === "modified" ? section.title : `${section.title} (${section.kind})`;
+      const table = document.createElement("table");
+      table.className = "report-table diff-table";
+      table.innerHTML = "<thead><tr><th>Key</th><th>Old</th><th>New</th></tr></thead>";
+      const tbody = document.createElement("tbody");
+      section.changes.forEach((change) => {
+        const row = document.createElement("tr");
+        const key = document.createElement("td");
+        key.className = "font-semibold text-muted";
+        key.textContent = change.key;
+        const kind = document.createElement("div");
+        kind.className = "diff-kind";
+        kind.textContent = change.kind;
+        key.appendChild(kind);
+        let oldText = change.old;
+        let newText = change.new;
+        if (change.fields) {
+          const lines = (side) => change.fields.map((field) => `${field.name}: ${field[side] ?? "—"}`).join("\n");
+          oldText = lines("old");
+          newText = lines("new");
+        }
+        const oldCell = diffCell(oldText, "diff-old");
+        const newCell = diffCell(newText, "diff-new");
+        if (change.fields) {
+          oldCell.title = change.old;
+          newCell.title = change.new;
+          oldCell.style.whiteSpace = newCell.style.whiteSpace = "pre-line";
+        }
+        [key, oldCell, newCell].forEach((cell) => row.appendChild(cell));
+        tbody.appendChild(row);
+      });
+      table.appendChild(tbody);
+      diffSections.appendChild(heading);
+      diffSections.appendChild(table);
+    });
+    diffView.hidden = false;
+    diffView.scrollIntoView({ behavior: "smooth", block: "start" });
+  }
+
+  function postDiff(format) {
+    return fetch(`/api/v1/diff?format=${format}`, {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(state.diffRequest),
+    });
+  }
+
+  // The live report is collected in the snapshot's mode, so both sides have the same detail. Both reports
+  // go into the request, so the Markdown download describes the diff on screen without collecting again.
+  function compareSnapshot(summary) {
+    if (!diffView) return;
+    Promise.all([
+      requestJson(`/api/v1/snapshots/${encodeURIComponent(summary.id)}`),
+      requestJson(`/api/v1/system?mode=${encodeURIComponent(summary.mode)}`),
+    ])
+      .then(([snapshot, live]) => {
+        state.diffRequest = { old: snapshot.report, new: live };
+        return postDiff("json").then((response) =>
+          response.json().then((payload) => {
+            if (!response.ok) throw new Error(payload.message || "Diff failed");
+            return payload;
+          })
+        );
+      })
+      .then(renderDiff)
+      .catch((err) => {
+        statusText.textContent = `Unable to compare snapshot: ${err.message}`;
+      });
+  }
+
+  function downloadDiffMarkdown() {
+    if (!state.diffRequest) return;
+    postDiff("md")
+      .then((response) => {
+        if (!response.ok) throw new Error("Markdown export failed");
+        const disposition = response.headers.get("Content-Disposition") || "";
+        const match = disposition.match(/filename="([^"]+)"/);
+        return response.blob().then((blob) => ({ blob, name: match ? match[1] : "inxi-dashboard-diff.md" }));
+      })
+      .then(({ blob, name }) => {
+        const link = document.createElement("a");
+        link.href = URL.createObjectURL(blob);
+        link.download = name;
+        link.click();
+        URL.revokeObjectURL(link.href);
+      })
+      .catch((err) => {
+        statusText.textContent = `Unable to download diff: ${err.message}`;
+      });
+  }
+
+  function deleteSnapshot(summary) {
+    const name = summary.label ? `"${summary.label}"` : `from ${new Date(summary.saved_utc).toLocaleString()}`;
+    if (!window.confirm(`Delete snapshot ${name}?`)) return;
+    requestJson(`/api/v1/snapshots/${encodeURIComponent(summary.id)}`, { method: "DELETE" })
+      .then(() => {
+        if (state.snapshot && state.snapshot.id === summary.id) refreshReport();
+        loadHistory(state.historyOffset);
+      })
+      .catch((err) => {
+        statusText.textContent = `Unable to delete snapshot: ${err.message}`;
+      });
   }
 
   themeSelect.addEventListener("change", (event) => {
@@ -813,11 +1469,66 @@
   modeSelect.addEventListener("change", (event) => {
     setMode(event.target.value);
     refreshReport();
+    if (state.stream) startLive();
   });
 
-  refreshButton.addEventListener("click", refreshReport);
+  if (searchForm && searchInput && searchResults) {
+    searchForm.addEventListener("submit", (event) => {
+      event.preventDefault();
+      runSearch(searchInput.value);
+    });
+    // Clearing the field (e.g. its "x" button) hides stale results.
+    searchInput.addEventListener("search", () => {
+      if (!searchInput.value) runSearch("");
+    });
+  }
+
+  if (exportSelect) {
+    exportSelect.addEventListener("change", updateDownloadLink);
+  }
+
+  if (forumCopyButton) {
+    forumCopyButton.addEventListener("click", copyForForum);
+  }
+
+  if (liveToggle) {
+    liveToggle.addEventListener("change", (event) => setLive(event.target.checked));
+  }
 
   loadTheme();
-  loadMode();
-  refreshReport();
+
+  if (state.offline) {
+    state.mode = embeddedReport.mode;
+    modeSelect.value = embeddedReport.mode;
+    showReport(embeddedReport);
+    statusText.hidden = false;
+    const dashboard = document.getElementById("offline-dashboard");
+    if (dashboard) dashboard.hidden = false;
+    return;
+  }
+
+  refreshButton.addEventListener("click", refreshReport);
+
+  if (snapshotForm) snapshotForm.addEventListener("submit", saveSnapshot);
+  if (diffMarkdownButton) diffMarkdownButton.addEventListener("click", downloadDiffMarkdown);
+  if (diffCloseButton) {
+    diffCloseButton.addEventListener("click", () => {
+      diffView.hidden = true;
+      state.diffRequest = null;
+    });
+  }
+  if (snapshotNewer) snapshotNewer.addEventListener("click", () => loadHistory(state.historyOffset - HISTORY_PAGE_SIZE));
+  if (snapshotOlder) snapshotOlder.addEventListener("click", () => loadHistory(state.historyOffset + HISTORY_PAGE_SIZE));
+
+  if (loginForm && loginToken) loginForm.addEventListener("submit", signIn);
+  if (logoutButton) logoutButton.addEventListener("click", signOut);
+
+  loadAccess().then(() => {
+    loadMode();
+    refreshReport();
+    loadHistory(0);
+    if (liveToggle && localStorage.getItem(LIVE_KEY) === "on") {
+      setLive(true);
+    }
+  });
 })();